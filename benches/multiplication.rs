@@ -0,0 +1,72 @@
+// Compares multiplication through a heap of products with the previous
+//   approach of merging each product term into the result one at a time.
+//
+// Run with `cargo bench`.
+
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Multiplication as it was done before, where every product term is
+//   added to a copy of the whole result so far.
+fn naive_mult(f: &Polynomial, g: &Polynomial) -> Polynomial {
+    let (t1, t2) = (&f.terms, &g.terms);
+    let mut c = Polynomial::zero(&f.ring);
+
+    let mut h = BinaryHeap::new();
+    let mut fs = vec![0; t1.len()];
+    for (i, t) in t1.iter().enumerate() {
+        h.push((mult_monoms(t, &t2[0]).unwrap(), i));
+    }
+
+    while let Some((d, s)) = h.pop() {
+        c = add_polys(&c, &Polynomial::from_monom(d)).unwrap();
+        if fs[s] < t2.len() - 1 {
+            fs[s] += 1;
+            h.push((mult_monoms(&t1[s], &t2[fs[s]]).unwrap(), s));
+        }
+    }
+    c
+}
+
+fn time<T, G: FnMut() -> T>(mut g: G) -> (T, Duration) {
+    let start = Instant::now();
+    let t = g();
+    (t, start.elapsed())
+}
+
+fn bench(name: &str, f: &Polynomial, g: &Polynomial) {
+    let (p1, naive) = time(|| naive_mult(f, g));
+    let (p2, heap) = time(|| mult_polys(f, g).unwrap());
+    assert!(p1 == p2);
+    println!("{:<36} naive {:>10.2?}   heap {:>10.2?}   speedup {:.1}x",
+             name, naive, heap, naive.as_secs_f64() / heap.as_secs_f64());
+}
+
+fn power(f: &Polynomial, n: usize) -> Polynomial {
+    (1..n).fold(f.clone(), |p, _| mult_polys(&p, f).unwrap())
+}
+
+fn main() {
+    let symbols = ["x", "y", "z", "t"].iter().map(|s| s.to_string()).collect();
+    let ring = Arc::new(Ring::new(symbols, MonomialOrdering::DegRevLex));
+
+    // Fateman's benchmark, f * (f + 1) for f = (1 + x + y + z + t)^n, where
+    //   many products share a monomial.
+    let s = Polynomial::from_string("1x^1y^0z^0t^0 + 1x^0y^1z^0t^0 + 1x^0y^0z^1t^0 + 1x^0y^0z^0t^1 + 1x^0y^0z^0t^0", &ring).unwrap();
+    let one = Polynomial::from_string("1x^0y^0z^0t^0", &ring).unwrap();
+    for n in [4, 5, 6] {
+        let f = power(&s, n);
+        let g = add_polys(&f, &one).unwrap();
+        bench(&format!("dense, {} x {} terms", f.terms.len(), g.terms.len()), &f, &g);
+    }
+
+    // Sparse factors with few coinciding products.
+    let f = Polynomial::from_string("1x^9y^0z^1t^0 + 3x^0y^7z^0t^2 + -1x^2y^2z^2t^2 + 5x^0y^0z^0t^5 + 1x^0y^0z^0t^0", &ring).unwrap();
+    let g = Polynomial::from_string("2x^4y^1z^0t^0 + 1x^0y^3z^3t^0 + -7x^1y^0z^0t^4 + 1x^0y^0z^1t^0", &ring).unwrap();
+    let (f, g) = (power(&f, 4), power(&g, 4));
+    bench(&format!("sparse, {} x {} terms", f.terms.len(), g.terms.len()), &f, &g);
+}
@@ -0,0 +1,144 @@
+// Compares reduction with geobuckets against the previous approach of
+//   subtracting each multiple from the whole remaining polynomial, on
+//   division by a basis and on Grobner bases of standard systems.
+//
+// Run with `cargo bench`.
+
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use rug::Rational;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Division as it was done before geobuckets, where every step re-merges
+//   the whole remaining polynomial.
+fn naive_remainder<F: Field>(f: &Polynomial<F>, g: &PolySet<F>) -> Polynomial<F> {
+    let mut r = Polynomial::zero(&f.ring);
+    let mut p = f.clone();
+
+    while !p.terms.is_empty() {
+        match g.0.iter().find(|h| monom_divides(&h.terms[0], &p.terms[0])) {
+            Some(h) => {
+                let cancel = Polynomial::from_monom(divide_monoms(&p.terms[0], &h.terms[0]).unwrap());
+                p = sub_polys(&p, &mult_polys(&cancel, h).unwrap()).unwrap();
+            },
+            None => {
+                let lt = p.lt().unwrap();
+                r = add_polys(&r, &lt).unwrap();
+                p = sub_polys(&p, &lt).unwrap();
+            },
+        }
+    }
+    r
+}
+
+// `grobner_basis` and `reduce` with the division above.
+fn naive_grobner_basis<F: Field>(ps: &PolySet<F>) -> PolySet<F> {
+    let mut s = ps.0.clone();
+    let mut g = PolySet(Vec::new());
+
+    while let Some(f) = s.pop() {
+        let r = naive_remainder(&f, &g);
+        if !r.terms.is_empty() {
+            for p in g.0.iter() {
+                let (a, b) = (r.terms[0].clone(), p.terms[0].clone());
+                if lcm(&a, &b).unwrap() != mult_monoms(&a, &b).unwrap() {
+                    s.push(s_poly(&r, p).unwrap());
+                }
+            }
+            g.0.push(r);
+        }
+    }
+
+    let mut gp = Vec::new();
+    for i in 0..g.0.len() {
+        let p = g.0.remove(i);
+        let r = naive_remainder(&p, &g);
+        g.0.insert(i, p);
+        if !r.terms.is_empty() {
+            let c = F::one(&r.ring.domain).div(&r.terms[0].coefficient);
+            gp.push(scalar_mult(&r, c));
+        }
+    }
+    PolySet(gp)
+}
+
+fn time<T, G: FnMut() -> T>(mut g: G) -> (T, Duration) {
+    let start = Instant::now();
+    let t = g();
+    (t, start.elapsed())
+}
+
+fn report(name: &str, naive: Duration, geobucket: Duration) {
+    println!("{:<34} naive {:>10.2?}   geobuckets {:>10.2?}   speedup {:.1}x",
+             name, naive, geobucket, naive.as_secs_f64() / geobucket.as_secs_f64());
+}
+
+fn ring<F: Coefficient>(symbols: &str, domain: F::Domain) -> Arc<Ring<F>> {
+    let symbols = symbols.chars().map(|c| c.to_string()).collect();
+    Arc::new(Ring::new(symbols, MonomialOrdering::DegRevLex).over::<F>(domain))
+}
+
+fn system<F: Coefficient>(ring: &Arc<Ring<F>>, fs: &[&str]) -> PolySet<F> {
+    PolySet(fs.iter().map(|f| Polynomial::from_string(f, ring).unwrap()).collect())
+}
+
+fn bench_division<F: Field>(name: &str, f: &Polynomial<F>, g: &PolySet<F>) {
+    let (r1, naive) = time(|| naive_remainder(f, g));
+    let (r2, geobucket) = time(|| divide_poly_set(f, &mut g.clone()).unwrap().1);
+    assert!(r1 == r2);
+    report(name, naive, geobucket);
+}
+
+fn bench_basis<F: Field>(name: &str, ps: &PolySet<F>) {
+    let (g1, naive) = time(|| naive_grobner_basis(ps));
+    let (g2, geobucket) = time(|| grobner_basis(ps).unwrap());
+    assert!(g1.0.len() == g2.0.len() && g1.0.iter().all(|p| g2.0.contains(p)));
+    report(name, naive, geobucket);
+}
+
+fn cyclic3<F: Coefficient>(ring: &Arc<Ring<F>>) -> PolySet<F> {
+    system(ring, &[
+        "1a^1b^0c^0 + 1a^0b^1c^0 + 1a^0b^0c^1",
+        "1a^1b^1c^0 + 1a^0b^1c^1 + 1a^1b^0c^1",
+        "1a^1b^1c^1 + -1a^0b^0c^0",
+    ])
+}
+
+fn katsura2<F: Coefficient>(ring: &Arc<Ring<F>>) -> PolySet<F> {
+    system(ring, &[
+        "1x^1y^0z^0 + 2x^0y^1z^0 + 2x^0y^0z^1 + -1x^0y^0z^0",
+        "1x^2y^0z^0 + 2x^0y^2z^0 + 2x^0y^0z^2 + -1x^1y^0z^0",
+        "2x^1y^1z^0 + 2x^0y^1z^1 + -1x^0y^1z^0",
+    ])
+}
+
+fn katsura3<F: Coefficient>(ring: &Arc<Ring<F>>) -> PolySet<F> {
+    system(ring, &[
+        "1x^1y^0z^0t^0 + 2x^0y^1z^0t^0 + 2x^0y^0z^1t^0 + 2x^0y^0z^0t^1 + -1x^0y^0z^0t^0",
+        "1x^2y^0z^0t^0 + 2x^0y^2z^0t^0 + 2x^0y^0z^2t^0 + 2x^0y^0z^0t^2 + -1x^1y^0z^0t^0",
+        "2x^1y^1z^0t^0 + 2x^0y^1z^1t^0 + 2x^0y^0z^1t^1 + -1x^0y^1z^0t^0",
+        "1x^0y^2z^0t^0 + 2x^1y^0z^1t^0 + 2x^0y^1z^0t^1 + -1x^0y^0z^1t^0",
+    ])
+}
+
+// (x + y + z + 1)^n
+fn power<F: Coefficient>(ring: &Arc<Ring<F>>, n: usize) -> Polynomial<F> {
+    let s = Polynomial::from_string("1x^1y^0z^0 + 1x^0y^1z^0 + 1x^0y^0z^1 + 1x^0y^0z^0", ring).unwrap();
+    (1..n).fold(s.clone(), |f, _| mult_polys(&f, &s).unwrap())
+}
+
+fn main() {
+    let q = ring::<Rational>("xyz", ());
+    bench_division("Q, (x+y+z+1)^12 mod katsura-2", &power(&q, 12), &grobner_basis(&katsura2(&q)).unwrap());
+    bench_basis("Q, katsura-2", &katsura2(&q));
+    bench_basis("Q, cyclic-3", &cyclic3(&ring::<Rational>("abc", ())));
+
+    let p = 32003;
+    let z = ring::<Zp>("xyz", p);
+    bench_division("Z/p, (x+y+z+1)^12 mod katsura-2", &power(&z, 12), &grobner_basis(&katsura2(&z)).unwrap());
+    bench_basis("Z/p, katsura-3", &katsura3(&ring::<Zp>("xyzt", p)));
+}
@@ -0,0 +1,415 @@
+extern crate rug;
+
+use super::error::PolyError;
+use super::fields::{Coefficient, Field};
+use super::operations::*;
+use super::polynomials::*;
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::sync::Arc;
+use rug::{Integer, Rational};
+
+// Operator overloads for polynomials and monomials, so that formulas can
+//   be written as `&f * &g - 3 * &h` instead of nested calls.
+//
+// The operators call the functions in `operations` and panic where those
+//   return an error, such as when the operands come from different rings
+//   or an exponent overflows. Code handling untrusted input should call
+//   the functions directly and handle the `PolyError`.
+//
+// Each operation is implemented on references, and the other
+//   combinations of owned and borrowed operands forward to it.
+
+fn check<T>(r: Result<T, PolyError>) -> T {
+    match r {
+        Ok(t) => t,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn scale_monom<F: Coefficient>(m: &Monomial<F>, c: &F) -> Monomial<F> {
+    Monomial { coefficient: m.coefficient.mul(c), degree: m.degree.clone(), ring: Arc::clone(&m.ring) }
+}
+
+fn invert<F: Field>(c: &F) -> F {
+    match c.checked_inv() {
+        Some(c) => c,
+        None => panic!("{}", PolyError::DivisionByZero),
+    }
+}
+
+// Implements a binary operator for the owned and mixed combinations of
+//   two types, given the implementation for two references.
+macro_rules! forward_binop {
+    ($imp:ident, $method:ident, $bound:ident, $lhs:ident, $rhs:ident, $out:ident) => {
+        impl<F: $bound> $imp<$rhs<F>> for $lhs<F> {
+            type Output = $out<F>;
+            fn $method(self, other: $rhs<F>) -> $out<F> {
+                $imp::$method(&self, &other)
+            }
+        }
+
+        impl<F: $bound> $imp<&$rhs<F>> for $lhs<F> {
+            type Output = $out<F>;
+            fn $method(self, other: &$rhs<F>) -> $out<F> {
+                $imp::$method(&self, other)
+            }
+        }
+
+        impl<F: $bound> $imp<$rhs<F>> for &$lhs<F> {
+            type Output = $out<F>;
+            fn $method(self, other: $rhs<F>) -> $out<F> {
+                $imp::$method(self, &other)
+            }
+        }
+    };
+}
+
+// Implements an assigning operator from the matching binary one, for
+//   owned and borrowed right hand sides.
+macro_rules! forward_assign {
+    ($imp:ident, $method:ident, $op:ident, $opm:ident, $bound:ident, $lhs:ident, $rhs:ident) => {
+        impl<F: $bound> $imp<&$rhs<F>> for $lhs<F> {
+            fn $method(&mut self, other: &$rhs<F>) {
+                *self = $op::$opm(&*self, other);
+            }
+        }
+
+        impl<F: $bound> $imp<$rhs<F>> for $lhs<F> {
+            fn $method(&mut self, other: $rhs<F>) {
+                *self = $op::$opm(&*self, &other);
+            }
+        }
+    };
+}
+
+// Polynomials with polynomials.
+
+impl<F: Coefficient> Add<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn add(self, other: &Polynomial<F>) -> Polynomial<F> {
+        check(add_polys(self, other))
+    }
+}
+
+impl<F: Coefficient> Sub<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn sub(self, other: &Polynomial<F>) -> Polynomial<F> {
+        check(sub_polys(self, other))
+    }
+}
+
+impl<F: Coefficient> Mul<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn mul(self, other: &Polynomial<F>) -> Polynomial<F> {
+        check(mult_polys(self, other))
+    }
+}
+
+// The quotient and remainder of `divide_polys`.
+impl<F: Field> Div<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn div(self, other: &Polynomial<F>) -> Polynomial<F> {
+        check(divide_polys(self, other)).0
+    }
+}
+
+impl<F: Field> Rem<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn rem(self, other: &Polynomial<F>) -> Polynomial<F> {
+        check(divide_polys(self, other)).1
+    }
+}
+
+forward_binop!(Add, add, Coefficient, Polynomial, Polynomial, Polynomial);
+forward_binop!(Sub, sub, Coefficient, Polynomial, Polynomial, Polynomial);
+forward_binop!(Mul, mul, Coefficient, Polynomial, Polynomial, Polynomial);
+forward_binop!(Div, div, Field, Polynomial, Polynomial, Polynomial);
+forward_binop!(Rem, rem, Field, Polynomial, Polynomial, Polynomial);
+
+forward_assign!(AddAssign, add_assign, Add, add, Coefficient, Polynomial, Polynomial);
+forward_assign!(SubAssign, sub_assign, Sub, sub, Coefficient, Polynomial, Polynomial);
+forward_assign!(MulAssign, mul_assign, Mul, mul, Coefficient, Polynomial, Polynomial);
+forward_assign!(DivAssign, div_assign, Div, div, Field, Polynomial, Polynomial);
+forward_assign!(RemAssign, rem_assign, Rem, rem, Field, Polynomial, Polynomial);
+
+impl<F: Coefficient> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn neg(self) -> Polynomial<F> {
+        scalar_mult(self, F::one(&self.ring.domain).neg())
+    }
+}
+
+impl<F: Coefficient> Neg for Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn neg(self) -> Polynomial<F> {
+        -&self
+    }
+}
+
+// Polynomials with monomials, treating the monomial as a polynomial with
+//   a single term.
+
+impl<F: Coefficient> Add<&Monomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn add(self, other: &Monomial<F>) -> Polynomial<F> {
+        self + &Polynomial::from_monom(other.clone())
+    }
+}
+
+impl<F: Coefficient> Sub<&Monomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn sub(self, other: &Monomial<F>) -> Polynomial<F> {
+        self - &Polynomial::from_monom(other.clone())
+    }
+}
+
+impl<F: Coefficient> Mul<&Monomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn mul(self, other: &Monomial<F>) -> Polynomial<F> {
+        self * &Polynomial::from_monom(other.clone())
+    }
+}
+
+forward_binop!(Add, add, Coefficient, Polynomial, Monomial, Polynomial);
+forward_binop!(Sub, sub, Coefficient, Polynomial, Monomial, Polynomial);
+forward_binop!(Mul, mul, Coefficient, Polynomial, Monomial, Polynomial);
+
+forward_assign!(AddAssign, add_assign, Add, add, Coefficient, Polynomial, Monomial);
+forward_assign!(SubAssign, sub_assign, Sub, sub, Coefficient, Polynomial, Monomial);
+forward_assign!(MulAssign, mul_assign, Mul, mul, Coefficient, Polynomial, Monomial);
+
+// Monomials with monomials. Sums and differences can have two terms, so
+//   they are polynomials, while products and quotients stay monomials.
+//   Dividing by a monomial that does not divide panics.
+
+impl<F: Coefficient> Add<&Monomial<F>> for &Monomial<F> {
+    type Output = Polynomial<F>;
+    fn add(self, other: &Monomial<F>) -> Polynomial<F> {
+        &Polynomial::from_monom(self.clone()) + other
+    }
+}
+
+impl<F: Coefficient> Sub<&Monomial<F>> for &Monomial<F> {
+    type Output = Polynomial<F>;
+    fn sub(self, other: &Monomial<F>) -> Polynomial<F> {
+        &Polynomial::from_monom(self.clone()) - other
+    }
+}
+
+impl<F: Coefficient> Mul<&Monomial<F>> for &Monomial<F> {
+    type Output = Monomial<F>;
+    fn mul(self, other: &Monomial<F>) -> Monomial<F> {
+        check(mult_monoms(self, other))
+    }
+}
+
+impl<F: Field> Div<&Monomial<F>> for &Monomial<F> {
+    type Output = Monomial<F>;
+    fn div(self, other: &Monomial<F>) -> Monomial<F> {
+        check(divide_monoms(self, other))
+    }
+}
+
+forward_binop!(Add, add, Coefficient, Monomial, Monomial, Polynomial);
+forward_binop!(Sub, sub, Coefficient, Monomial, Monomial, Polynomial);
+forward_binop!(Mul, mul, Coefficient, Monomial, Monomial, Monomial);
+forward_binop!(Div, div, Field, Monomial, Monomial, Monomial);
+
+forward_assign!(MulAssign, mul_assign, Mul, mul, Coefficient, Monomial, Monomial);
+forward_assign!(DivAssign, div_assign, Div, div, Field, Monomial, Monomial);
+
+impl<F: Coefficient> Neg for &Monomial<F> {
+    type Output = Monomial<F>;
+    fn neg(self) -> Monomial<F> {
+        Monomial { coefficient: self.coefficient.neg(), degree: self.degree.clone(), ring: Arc::clone(&self.ring) }
+    }
+}
+
+impl<F: Coefficient> Neg for Monomial<F> {
+    type Output = Monomial<F>;
+    fn neg(self) -> Monomial<F> {
+        -&self
+    }
+}
+
+// Scalars from the coefficient field. Dividing by a scalar multiplies by
+//   its inverse, and the remainder is always zero, as for division by the
+//   constant polynomial. Both panic on a zero scalar.
+
+impl<F: Coefficient> Mul<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn mul(self, c: F) -> Polynomial<F> {
+        scalar_mult(self, c)
+    }
+}
+
+impl<F: Field> Div<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn div(self, c: F) -> Polynomial<F> {
+        scalar_mult(self, invert(&c))
+    }
+}
+
+impl<F: Field> Rem<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn rem(self, c: F) -> Polynomial<F> {
+        invert(&c);
+        Polynomial::zero(&self.ring)
+    }
+}
+
+impl<F: Coefficient> Mul<F> for &Monomial<F> {
+    type Output = Monomial<F>;
+    fn mul(self, c: F) -> Monomial<F> {
+        scale_monom(self, &c)
+    }
+}
+
+impl<F: Field> Div<F> for &Monomial<F> {
+    type Output = Monomial<F>;
+    fn div(self, c: F) -> Monomial<F> {
+        scale_monom(self, &invert(&c))
+    }
+}
+
+// Implements a scalar operator for an owned left hand side, and its
+//   assigning form, from the implementation on a reference.
+macro_rules! forward_scalar {
+    ($imp:ident, $method:ident, $assign:ident, $assign_method:ident, $bound:ident, $lhs:ident, $scalar:ty) => {
+        impl<F: $bound> $imp<$scalar> for $lhs<F> {
+            type Output = $lhs<F>;
+            fn $method(self, c: $scalar) -> $lhs<F> {
+                $imp::$method(&self, c)
+            }
+        }
+
+        impl<F: $bound> $assign<$scalar> for $lhs<F> {
+            fn $assign_method(&mut self, c: $scalar) {
+                *self = $imp::$method(&*self, c);
+            }
+        }
+    };
+}
+
+forward_scalar!(Mul, mul, MulAssign, mul_assign, Coefficient, Polynomial, F);
+forward_scalar!(Div, div, DivAssign, div_assign, Field, Polynomial, F);
+forward_scalar!(Rem, rem, RemAssign, rem_assign, Field, Polynomial, F);
+forward_scalar!(Mul, mul, MulAssign, mul_assign, Coefficient, Monomial, F);
+forward_scalar!(Div, div, DivAssign, div_assign, Field, Monomial, F);
+
+// Scalars on the left. The orphan rules only allow these for concrete
+//   coefficient types.
+macro_rules! scalar_on_left {
+    ($scalar:ty) => {
+        impl Mul<&Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+            fn mul(self, f: &Polynomial<$scalar>) -> Polynomial<$scalar> {
+                f * self
+            }
+        }
+
+        impl Mul<Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+            fn mul(self, f: Polynomial<$scalar>) -> Polynomial<$scalar> {
+                &f * self
+            }
+        }
+
+        impl Mul<&Monomial<$scalar>> for $scalar {
+            type Output = Monomial<$scalar>;
+            fn mul(self, m: &Monomial<$scalar>) -> Monomial<$scalar> {
+                m * self
+            }
+        }
+
+        impl Mul<Monomial<$scalar>> for $scalar {
+            type Output = Monomial<$scalar>;
+            fn mul(self, m: Monomial<$scalar>) -> Monomial<$scalar> {
+                &m * self
+            }
+        }
+    };
+}
+
+scalar_on_left!(Rational);
+scalar_on_left!(Integer);
+
+// Machine integers, mapped into the coefficient field with
+//   `Coefficient::from_integer`, so `2 * &f` works over any field. Only
+//   i64 is supported, since with several integer types a literal would
+//   need a suffix.
+macro_rules! integer_scalar {
+    ($int:ty) => {
+        impl<F: Coefficient> Mul<$int> for &Polynomial<F> {
+            type Output = Polynomial<F>;
+            fn mul(self, n: $int) -> Polynomial<F> {
+                self * F::from_integer(&Integer::from(n), &self.ring.domain)
+            }
+        }
+
+        impl<F: Field> Div<$int> for &Polynomial<F> {
+            type Output = Polynomial<F>;
+            fn div(self, n: $int) -> Polynomial<F> {
+                self / F::from_integer(&Integer::from(n), &self.ring.domain)
+            }
+        }
+
+        impl<F: Field> Rem<$int> for &Polynomial<F> {
+            type Output = Polynomial<F>;
+            fn rem(self, n: $int) -> Polynomial<F> {
+                self % F::from_integer(&Integer::from(n), &self.ring.domain)
+            }
+        }
+
+        impl<F: Coefficient> Mul<$int> for &Monomial<F> {
+            type Output = Monomial<F>;
+            fn mul(self, n: $int) -> Monomial<F> {
+                self * F::from_integer(&Integer::from(n), &self.ring.domain)
+            }
+        }
+
+        impl<F: Field> Div<$int> for &Monomial<F> {
+            type Output = Monomial<F>;
+            fn div(self, n: $int) -> Monomial<F> {
+                self / F::from_integer(&Integer::from(n), &self.ring.domain)
+            }
+        }
+
+        forward_scalar!(Mul, mul, MulAssign, mul_assign, Coefficient, Polynomial, $int);
+        forward_scalar!(Div, div, DivAssign, div_assign, Field, Polynomial, $int);
+        forward_scalar!(Rem, rem, RemAssign, rem_assign, Field, Polynomial, $int);
+        forward_scalar!(Mul, mul, MulAssign, mul_assign, Coefficient, Monomial, $int);
+        forward_scalar!(Div, div, DivAssign, div_assign, Field, Monomial, $int);
+
+        impl<F: Coefficient> Mul<&Polynomial<F>> for $int {
+            type Output = Polynomial<F>;
+            fn mul(self, f: &Polynomial<F>) -> Polynomial<F> {
+                f * self
+            }
+        }
+
+        impl<F: Coefficient> Mul<Polynomial<F>> for $int {
+            type Output = Polynomial<F>;
+            fn mul(self, f: Polynomial<F>) -> Polynomial<F> {
+                &f * self
+            }
+        }
+
+        impl<F: Coefficient> Mul<&Monomial<F>> for $int {
+            type Output = Monomial<F>;
+            fn mul(self, m: &Monomial<F>) -> Monomial<F> {
+                m * self
+            }
+        }
+
+        impl<F: Coefficient> Mul<Monomial<F>> for $int {
+            type Output = Monomial<F>;
+            fn mul(self, m: Monomial<F>) -> Monomial<F> {
+                &m * self
+            }
+        }
+    };
+}
+
+integer_scalar!(i64);
@@ -0,0 +1,143 @@
+extern crate rug;
+
+use rug::Integer;
+
+use super::fields::{Coefficient, Field};
+
+// Helpers for dense polynomials in one variable, stored as coefficient
+//   vectors from the constant term up with no trailing zeros. Finite
+//   fields and number fields keep their elements this way, and the
+//   factorization code works with them over Z/p and Z.
+
+pub(crate) fn trim<F: Coefficient>(mut v: Vec<F>) -> Vec<F> {
+    while v.last().is_some_and(|c| c.is_zero()) {
+        v.pop();
+    }
+    v
+}
+
+pub(crate) fn add<F: Coefficient>(a: &[F], b: &[F]) -> Vec<F> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut v = long.to_vec();
+    for (x, y) in v.iter_mut().zip(short) {
+        *x = x.add(y);
+    }
+    trim(v)
+}
+
+pub(crate) fn neg<F: Coefficient>(a: &[F]) -> Vec<F> {
+    a.iter().map(|x| x.neg()).collect()
+}
+
+pub(crate) fn sub<F: Coefficient>(a: &[F], b: &[F]) -> Vec<F> {
+    add(a, &neg(b))
+}
+
+pub(crate) fn scale<F: Coefficient>(a: &[F], c: &F) -> Vec<F> {
+    trim(a.iter().map(|x| x.mul(c)).collect())
+}
+
+pub(crate) fn mul<F: Coefficient>(a: &[F], b: &[F], d: &F::Domain) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut v = vec![F::zero(d); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            v[i + j] = v[i + j].add(&x.mul(y));
+        }
+    }
+    trim(v)
+}
+
+// Euclidean division by b, whose leading coefficient has inverse u.
+fn divmod_by<F: Coefficient>(a: &[F], b: &[F], u: &F, d: &F::Domain) -> (Vec<F>, Vec<F>) {
+    let mut r = trim(a.to_vec());
+    let n = b.len();
+    if r.len() < n {
+        return (Vec::new(), r);
+    }
+
+    let mut q = vec![F::zero(d); r.len() - n + 1];
+    for k in (0..q.len()).rev() {
+        let c = r[k + n - 1].mul(u);
+        if c.is_zero() {
+            continue;
+        }
+        for (i, y) in b.iter().enumerate() {
+            r[k + i] = r[k + i].sub(&c.mul(y));
+        }
+        q[k] = c;
+    }
+    r.truncate(n - 1);
+    (trim(q), trim(r))
+}
+
+// Euclidean division by a monic polynomial, which needs no inverses, so
+//   it works over Z too.
+pub(crate) fn divmod_monic<F: Coefficient>(a: &[F], b: &[F], d: &F::Domain) -> (Vec<F>, Vec<F>) {
+    divmod_by(a, b, &F::one(d), d)
+}
+
+// Euclidean division, returning the quotient and remainder.
+//
+// @pre b is nonzero.
+pub(crate) fn divmod<F: Field>(a: &[F], b: &[F], d: &F::Domain) -> (Vec<F>, Vec<F>) {
+    divmod_by(a, b, &F::one(d).div(&b[b.len() - 1]), d)
+}
+
+pub(crate) fn monic<F: Field>(a: &[F], d: &F::Domain) -> Vec<F> {
+    match a.last() {
+        Some(c) => scale(a, &F::one(d).div(c)),
+        None => Vec::new(),
+    }
+}
+
+// Monic greatest common divisor, by Euclid's algorithm.
+pub(crate) fn gcd<F: Field>(a: &[F], b: &[F], d: &F::Domain) -> Vec<F> {
+    let mut a = trim(a.to_vec());
+    let mut b = trim(b.to_vec());
+    while !b.is_empty() {
+        let r = divmod(&a, &b, d).1;
+        a = b;
+        b = r;
+    }
+    monic(&a, d)
+}
+
+// The monic gcd g of a and b, with s and t such that s a + t b = g, from
+//   the extended Euclidean algorithm.
+pub(crate) fn ext_gcd<F: Field>(a: &[F], b: &[F], d: &F::Domain) -> (Vec<F>, Vec<F>, Vec<F>) {
+    let (mut r0, mut r1) = (trim(a.to_vec()), trim(b.to_vec()));
+    let (mut s0, mut s1) = (vec![F::one(d)], Vec::new());
+    let (mut t0, mut t1) = (Vec::new(), vec![F::one(d)]);
+    while !r1.is_empty() {
+        let (q, r) = divmod(&r0, &r1, d);
+        r0 = r1;
+        r1 = r;
+        let s = sub(&s0, &mul(&q, &s1, d));
+        s0 = s1;
+        s1 = s;
+        let t = sub(&t0, &mul(&q, &t1, d));
+        t0 = t1;
+        t1 = t;
+    }
+
+    let c = match r0.last() {
+        Some(c) => F::one(d).div(c),
+        None => return (r0, s0, t0),
+    };
+    (scale(&r0, &c), scale(&s0, &c), scale(&t0, &c))
+}
+
+// a^e mod m, by repeated squaring.
+pub(crate) fn pow_mod<F: Field>(a: &[F], e: &Integer, m: &[F], d: &F::Domain) -> Vec<F> {
+    let mut acc = vec![F::one(d)];
+    for i in (0..e.significant_bits()).rev() {
+        acc = divmod(&mul(&acc, &acc, d), m, d).1;
+        if e.get_bit(i) {
+            acc = divmod(&mul(&acc, a, d), m, d).1;
+        }
+    }
+    acc
+}
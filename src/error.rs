@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use super::exponents::ExponentError;
+use super::fields::FieldError;
+use super::parser::ParseError;
+use super::polynomials::{MonomError, OrderingError};
+
+// Errors for the crate as a whole. Operations on polynomials return these,
+//   and the errors from parsing and from building rings and fields convert
+//   into them, so a caller can handle everything in one place.
+//
+//   A few conveniences panic instead: `Field::div` and the arithmetic
+//   operators on division by zero, where `checked_div` and the functions
+//   in `operations` return DivisionByZero, and arithmetic on rational
+//   functions whose parameter degrees pass 2^63.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PolyError {
+    // The operands come from different rings.
+    RingMismatch,
+    // Two exponent vectors have different numbers of variables.
+    LengthMismatch,
+    // An exponent is too large for the ring's exponent width.
+    ExponentOverflow,
+    // A monomial was divided by one that does not divide it.
+    NotDivisible,
+    // Division by the zero polynomial or by a zero coefficient.
+    DivisionByZero,
+    UnknownVariable(String),
+    Parse(MonomError),
+    Syntax(ParseError),
+    Ordering(OrderingError),
+    Field(FieldError),
+}
+
+impl Display for PolyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolyError::RingMismatch => write!(f, "Operands belong to different rings"),
+            PolyError::LengthMismatch => write!(f, "Degree vectors have different lengths"),
+            PolyError::ExponentOverflow => write!(f, "Exponent too large for the ring"),
+            PolyError::NotDivisible => write!(f, "Monomial does not divide"),
+            PolyError::DivisionByZero => write!(f, "Division by zero"),
+            PolyError::UnknownVariable(v) => write!(f, "Unknown variable {}", v),
+            PolyError::Parse(e) => write!(f, "Invalid polynomial: {:?}", e),
+            PolyError::Syntax(e) => write!(f, "Invalid syntax: {}", e),
+            PolyError::Ordering(e) => write!(f, "Invalid monomial ordering: {:?}", e),
+            PolyError::Field(e) => write!(f, "Invalid coefficient field: {:?}", e),
+        }
+    }
+}
+
+impl Error for PolyError {}
+
+impl From<ExponentError> for PolyError {
+    fn from(e: ExponentError) -> Self {
+        match e {
+            ExponentError::Overflow => PolyError::ExponentOverflow,
+            ExponentError::NotDivisible => PolyError::NotDivisible,
+        }
+    }
+}
+
+impl From<MonomError> for PolyError {
+    fn from(e: MonomError) -> Self {
+        PolyError::Parse(e)
+    }
+}
+
+impl From<ParseError> for PolyError {
+    fn from(e: ParseError) -> Self {
+        PolyError::Syntax(e)
+    }
+}
+
+impl From<OrderingError> for PolyError {
+    fn from(e: OrderingError) -> Self {
+        PolyError::Ordering(e)
+    }
+}
+
+impl From<FieldError> for PolyError {
+    fn from(e: FieldError) -> Self {
+        PolyError::Field(e)
+    }
+}
@@ -0,0 +1,352 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+
+// Exponent vectors packed several to a machine word, so that monomial
+//   products, quotients, divisibility checks and comparisons work on whole
+//   words at a time instead of on each variable.
+//
+// Each exponent takes a lane of 8, 16, 32 or 64 bits, chosen per ring,
+//   whose top bit is a guard. Exponents are kept below the guard, so lanes
+//   never carry into each other, and a product whose guard bit is set has
+//   overflowed.
+//
+// Variables are packed from the most significant lane down, so comparing
+//   the words as integers compares the exponents lexicographically.
+
+// Up to this many words are stored inline, covering eight variables with
+//   the default 32 bit lanes without a heap allocation.
+const INLINE: usize = 4;
+
+// The largest exponent `Exponents::new` takes. The default 32 bit lanes
+//   hold any u16, so the range of the original u16 vectors is kept.
+pub const MAX_EXPONENT: u16 = u16::MAX;
+
+// The size of each exponent. Narrow lanes fit more variables in a word,
+//   and wide ones allow larger exponents, up to one less than a power of
+//   two since the top bit of each lane is the guard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExponentWidth {
+    U8,
+    U16,
+    #[default]
+    U32,
+    U64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExponentError {
+    Overflow,
+    NotDivisible,
+}
+
+impl ExponentWidth {
+    pub fn bits(self) -> usize {
+        match self {
+            ExponentWidth::U8 => 8,
+            ExponentWidth::U16 => 16,
+            ExponentWidth::U32 => 32,
+            ExponentWidth::U64 => 64,
+        }
+    }
+
+    // The largest exponent a single variable can have.
+    pub fn max(self) -> u64 {
+        self.mask() >> 1
+    }
+
+    fn lanes(self) -> usize {
+        64 / self.bits()
+    }
+
+    fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits())
+    }
+
+    // The lowest and the highest bit of every lane.
+    fn low(self) -> u64 {
+        u64::MAX / self.mask()
+    }
+
+    fn guard(self) -> u64 {
+        self.low() << (self.bits() - 1)
+    }
+}
+
+#[derive(Clone)]
+enum Words {
+    Inline([u64; INLINE]),
+    Heap(Box<[u64]>),
+}
+
+#[derive(Clone)]
+pub struct Exponents {
+    words: Words,
+    len: usize,
+    width: ExponentWidth,
+    // Total degree, cached for graded orderings.
+    total: u64,
+}
+
+impl Exponents {
+    // Packs an exponent vector with the default lanes, which hold any u16
+    //   and have room for the total degree, so this cannot fail. Use
+    //   `with_width` for other widths or larger exponents.
+    pub fn new(v: &[u16]) -> Exponents {
+        let v: Vec<u64> = v.iter().map(|d| u64::from(*d)).collect();
+        match Exponents::with_width(&v, ExponentWidth::default()) {
+            Ok(e) => e,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    // Packs an exponent vector with lanes of the given width.
+    pub fn with_width(v: &[u64], width: ExponentWidth) -> Result<Exponents, ExponentError> {
+        let mut e = Exponents::zero_with_width(v.len(), width);
+        for (i, d) in v.iter().enumerate() {
+            if *d > width.max() {
+                return Err(ExponentError::Overflow);
+            }
+            let s = e.shift(i);
+            e.words_mut()[i / width.lanes()] |= d << s;
+            e.total = e.total.checked_add(*d).ok_or(ExponentError::Overflow)?;
+        }
+        Ok(e)
+    }
+
+    // The exponents of the monomial 1 in n variables.
+    pub fn zero(n: usize) -> Exponents {
+        Exponents::zero_with_width(n, ExponentWidth::default())
+    }
+
+    pub fn zero_with_width(n: usize, width: ExponentWidth) -> Exponents {
+        let k = n.div_ceil(width.lanes());
+        let words = if k <= INLINE {
+            Words::Inline([0; INLINE])
+        } else {
+            Words::Heap(vec![0; k].into_boxed_slice())
+        };
+        Exponents { words, len: n, width, total: 0 }
+    }
+
+    // Repacks the exponents with lanes of another width.
+    pub fn to_width(&self, width: ExponentWidth) -> Result<Exponents, ExponentError> {
+        Exponents::with_width(&self.to_vec(), width)
+    }
+
+    fn words(&self) -> &[u64] {
+        match &self.words {
+            Words::Inline(w) => w,
+            Words::Heap(w) => w,
+        }
+    }
+
+    fn words_mut(&mut self) -> &mut [u64] {
+        match &mut self.words {
+            Words::Inline(w) => w,
+            Words::Heap(w) => w,
+        }
+    }
+
+    fn shift(&self, i: usize) -> usize {
+        let lanes = self.width.lanes();
+        self.width.bits() * (lanes - 1 - i % lanes)
+    }
+
+    // Sets the low bit of each lane of a where the lane is at least the
+    //   matching lane of b.
+    fn lanes_ge(&self, a: u64, b: u64) -> u64 {
+        let guard = self.width.guard();
+        (((a | guard) - b) & guard) >> (self.width.bits() - 1)
+    }
+
+    // Brings two vectors to the same lane width, widening the narrower
+    //   one, which always succeeds.
+    fn aligned<'a>(&'a self, other: &'a Self) -> (Cow<'a, Exponents>, Cow<'a, Exponents>) {
+        match self.width.bits().cmp(&other.width.bits()) {
+            Ordering::Equal => (Cow::Borrowed(self), Cow::Borrowed(other)),
+            Ordering::Less => match self.to_width(other.width) {
+                Ok(e) => (Cow::Owned(e), Cow::Borrowed(other)),
+                Err(_) => unreachable!(),
+            },
+            Ordering::Greater => match other.to_width(self.width) {
+                Ok(e) => (Cow::Borrowed(self), Cow::Owned(e)),
+                Err(_) => unreachable!(),
+            },
+        }
+    }
+
+    // Builds a vector of the same length from a word-wise operation.
+    fn map2<G: Fn(&Self, u64, u64) -> u64>(&self, other: &Self, g: G) -> Exponents {
+        let (a, b) = self.aligned(other);
+        let mut e = a.into_owned();
+        for (x, y) in e.words_mut().iter_mut().zip(b.words()) {
+            *x = g(&b, *x, *y);
+        }
+        e.total = e.iter().sum();
+        e
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn width(&self) -> ExponentWidth {
+        self.width
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn get(&self, i: usize) -> u64 {
+        (self.words()[i / self.width.lanes()] >> self.shift(i)) & self.width.mask()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..self.len).map(move |i| self.get(i))
+    }
+
+    pub fn to_vec(&self) -> Vec<u64> {
+        self.iter().collect()
+    }
+
+    // Exponents of the product of two monomials, or an error if one of
+    //   them does not fit in its lane.
+    pub fn checked_mul(&self, other: &Self) -> Result<Exponents, ExponentError> {
+        let (a, b) = self.aligned(other);
+        let guard = a.width.guard();
+        let mut e = a.into_owned();
+        for (x, y) in e.words_mut().iter_mut().zip(b.words()) {
+            *x += *y;
+            if *x & guard != 0 {
+                return Err(ExponentError::Overflow);
+            }
+        }
+        e.total = e.total.checked_add(b.total).ok_or(ExponentError::Overflow)?;
+        Ok(e)
+    }
+
+    // Exponents of the quotient of two monomials, or an error if other
+    //   does not divide self.
+    pub fn checked_div(&self, other: &Self) -> Result<Exponents, ExponentError> {
+        if !other.divides(self) {
+            return Err(ExponentError::NotDivisible);
+        }
+        let (a, b) = self.aligned(other);
+        let mut e = a.into_owned();
+        for (x, y) in e.words_mut().iter_mut().zip(b.words()) {
+            *x -= *y;
+        }
+        e.total -= b.total;
+        Ok(e)
+    }
+
+    // Checks whether this monomial divides another, i.e. whether none of
+    //   its exponents is larger.
+    pub fn divides(&self, other: &Self) -> bool {
+        if self.total > other.total {
+            return false;
+        }
+        let (a, b) = self.aligned(other);
+        let low = a.width.low();
+        a.words().iter().zip(b.words()).all(|(x, y)| a.lanes_ge(*y, *x) == low)
+    }
+
+    pub fn lcm(&self, other: &Self) -> Exponents {
+        self.map2(other, |e, a, b| {
+            let m = e.lanes_ge(a, b) * e.width.mask();
+            (a & m) | (b & !m)
+        })
+    }
+
+    pub fn gcd(&self, other: &Self) -> Exponents {
+        self.map2(other, |e, a, b| {
+            let m = e.lanes_ge(a, b) * e.width.mask();
+            (b & m) | (a & !m)
+        })
+    }
+
+    // Lexicographic comparison, with the first variable the most
+    //   significant.
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        let (a, b) = self.aligned(other);
+        a.words().cmp(b.words())
+    }
+
+    // Reverse lexicographic comparison as used by grevlex: the monomial
+    //   with the smaller exponent in the last variable where they differ
+    //   is the larger one.
+    pub fn cmp_revlex(&self, other: &Self) -> Ordering {
+        let (a, b) = self.aligned(other);
+        let (bits, mask) = (a.width.bits(), a.width.mask());
+        for (x, y) in a.words().iter().zip(b.words()).rev() {
+            let d = x ^ y;
+            if d != 0 {
+                let s = (d.trailing_zeros() as usize / bits) * bits;
+                return ((y >> s) & mask).cmp(&((x >> s) & mask));
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl From<Vec<u16>> for Exponents {
+    fn from(v: Vec<u16>) -> Self {
+        Exponents::new(&v)
+    }
+}
+
+impl From<&[u16]> for Exponents {
+    fn from(v: &[u16]) -> Self {
+        Exponents::new(v)
+    }
+}
+
+// Vectors of different lengths are compared as if the shorter one were
+//   padded with zeros.
+impl PartialEq for Exponents {
+    fn eq(&self, other: &Self) -> bool {
+        if self.total != other.total {
+            return false;
+        }
+        let (a, b) = self.aligned(other);
+        let (a, b) = (a.words(), b.words());
+        if a.len() == b.len() {
+            a == b
+        } else {
+            (0..a.len().max(b.len())).all(|i| a.get(i).unwrap_or(&0) == b.get(i).unwrap_or(&0))
+        }
+    }
+}
+
+impl Eq for Exponents {}
+
+// Trailing zeros are left out of the hash, to agree with equality between
+//   vectors of different lengths.
+impl Hash for Exponents {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let n = (0..self.len).rev().find(|&i| self.get(i) != 0).map_or(0, |i| i + 1);
+        n.hash(state);
+        for i in 0..n {
+            self.get(i).hash(state);
+        }
+    }
+}
+
+impl PartialEq<Vec<u16>> for Exponents {
+    fn eq(&self, other: &Vec<u16>) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == u64::from(*b))
+    }
+}
+
+impl Debug for Exponents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,284 @@
+extern crate rand;
+extern crate rug;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rug::{Integer, Rational};
+
+use super::dense;
+use super::fields::{is_prime, Coefficient, Zp};
+
+// Irreducibility over Q, with Zassenhaus' algorithm. The polynomial is
+//   factored modulo a small prime, the factors are lifted to a power of
+//   the prime large enough to bound the coefficients of any factor over Z,
+//   and products of the lifted factors are tried as factors over Z.
+//
+//   See von zur Gathen and Gerhard, Modern Computer Algebra, chapters 14
+//   and 15.
+
+// Polynomials over Z / (m) are dense polynomials over Z with coefficients
+//   in [0, m), reduced after each operation.
+
+fn reduce(a: &[Integer], m: &Integer) -> Vec<Integer> {
+    let v = a.iter()
+        .map(|c| {
+            let r = Integer::from(c % m);
+            if r < 0 { r + m } else { r }
+        })
+        .collect();
+    dense::trim(v)
+}
+
+fn add_z(a: &[Integer], b: &[Integer], m: &Integer) -> Vec<Integer> {
+    reduce(&dense::add(a, b), m)
+}
+
+fn mul_z(a: &[Integer], b: &[Integer], m: &Integer) -> Vec<Integer> {
+    reduce(&dense::mul(a, b, &()), m)
+}
+
+// Euclidean division by a monic polynomial, returning the quotient and
+//   remainder.
+fn divmod_z(a: &[Integer], b: &[Integer], m: &Integer) -> (Vec<Integer>, Vec<Integer>) {
+    let (q, r) = dense::divmod_monic(a, b, &());
+    (reduce(&q, m), reduce(&r, m))
+}
+
+fn to_fp(a: &[Integer], p: u64) -> Vec<Zp> {
+    dense::trim(a.iter().map(|c| Zp::from_integer(c, &p)).collect())
+}
+
+fn from_fp(a: &[Zp]) -> Vec<Integer> {
+    a.iter().map(|c| Integer::from(c.value())).collect()
+}
+
+// Splits a monic squarefree polynomial into the products of its
+//   irreducible factors of each degree, using that x^(p^d) - x is the
+//   product of the monic irreducibles of degree dividing d.
+fn distinct_degree(f: &[Zp], p: u64) -> Vec<(Vec<Zp>, usize)> {
+    let x = vec![Zp::zero(&p), Zp::one(&p)];
+    let mut g = f.to_vec();
+    let mut h = x.clone();
+    let mut out = Vec::new();
+    let mut d = 0;
+    while g.len() > 2 * (d + 1) {
+        d += 1;
+        h = dense::pow_mod(&h, &Integer::from(p), &g, &p);
+        let c = dense::gcd(&g, &dense::sub(&h, &x), &p);
+        if c.len() > 1 {
+            g = dense::divmod(&g, &c, &p).0;
+            h = dense::divmod(&h, &g, &p).1;
+            out.push((c, d));
+        }
+    }
+    if g.len() > 1 {
+        let d = g.len() - 1;
+        out.push((g, d));
+    }
+    out
+}
+
+// Splits a product of irreducibles of degree d with Cantor and Zassenhaus'
+//   method: for random a, gcd(f, a^((p^d - 1) / 2) - 1) is a proper factor
+//   about half of the time. The prime must be odd.
+fn equal_degree(f: Vec<Zp>, d: usize, p: u64, rng: &mut StdRng, out: &mut Vec<Vec<Zp>>) {
+    let n = f.len() - 1;
+    if n == d {
+        out.push(f);
+        return;
+    }
+
+    let e = (Integer::from(Integer::u_pow_u(p as u32, d as u32)) - 1u32) / 2u32;
+    loop {
+        let a = dense::trim((0..n).map(|_| Zp::new(rng.gen_range(0..p) as i64, p)).collect());
+        if a.len() < 2 {
+            continue;
+        }
+        let b = dense::pow_mod(&a, &e, &f, &p);
+        let c = dense::gcd(&f, &dense::sub(&b, &[Zp::one(&p)]), &p);
+        if c.len() > 1 && c.len() < f.len() {
+            let q = dense::divmod(&f, &c, &p).0;
+            equal_degree(c, d, p, rng, out);
+            equal_degree(q, d, p, rng, out);
+            return;
+        }
+    }
+}
+
+// The monic irreducible factors of a monic squarefree polynomial.
+fn factor_mod(f: &[Zp], p: u64) -> Vec<Vec<Zp>> {
+    let mut rng = StdRng::seed_from_u64(p);
+    let mut out = Vec::new();
+    for (g, d) in distinct_degree(f, p) {
+        equal_degree(g, d, p, &mut rng, &mut out);
+    }
+    out
+}
+
+// Lifts f = g h mod p to f = g h mod p^k, for g monic and coprime to h
+//   mod p. Each step solves sigma g + tau h = (f - g h) / p^j mod p and
+//   adds p^j times the solution to the factors.
+fn hensel(f: &[Integer], g: &[Zp], h: &[Zp], p: u64, k: u32) -> (Vec<Integer>, Vec<Integer>) {
+    let (_, s, t) = dense::ext_gcd(g, h, &p);
+    let (s, t) = (from_fp(&s), from_fp(&t));
+    let (g0, h0) = (from_fp(g), from_fp(h));
+    let pz = Integer::from(p);
+
+    let (mut g, mut h) = (g0.clone(), h0.clone());
+    let mut q = pz.clone();
+    for _ in 1..k {
+        let gh = mul_z(&g, &h, &Integer::from(&q * &pz));
+        let n = f.len().max(gh.len());
+        let e: Vec<Integer> = (0..n)
+            .map(|i| {
+                let x = f.get(i).cloned().unwrap_or_default();
+                let y = gh.get(i).cloned().unwrap_or_default();
+                (x - y).div_exact(&q)
+            })
+            .collect();
+        let e = reduce(&e, &pz);
+
+        let (d, tau) = divmod_z(&mul_z(&t, &e, &pz), &g0, &pz);
+        let sigma = add_z(&mul_z(&s, &e, &pz), &mul_z(&d, &h0, &pz), &pz);
+
+        let step = |a: &[Integer], b: &[Integer], q: &Integer| {
+            let b: Vec<Integer> = b.iter().map(|c| Integer::from(c * q)).collect();
+            add_z(a, &b, &Integer::from(q * &pz))
+        };
+        g = step(&g, &tau, &q);
+        h = step(&h, &sigma, &q);
+        q *= &pz;
+    }
+    (g, h)
+}
+
+// Lifts the factorization of f into the given monic factors mod p to one
+//   mod p^k, splitting the factors in halves and lifting each split.
+fn lift(f: &[Integer], factors: &[Vec<Zp>], p: u64, k: u32, pk: &Integer, out: &mut Vec<Vec<Integer>>) {
+    if factors.len() == 1 {
+        let c = match f[f.len() - 1].clone().invert(pk) {
+            Ok(c) => c,
+            Err(_) => unreachable!(),
+        };
+        let f: Vec<Integer> = f.iter().map(|x| Integer::from(x * &c)).collect();
+        out.push(reduce(&f, pk));
+        return;
+    }
+
+    let (a, b) = factors.split_at(factors.len() / 2);
+    let g = a.iter().fold(vec![Zp::one(&p)], |g, x| dense::mul(&g, x, &p));
+    let h = dense::divmod(&to_fp(f, p), &g, &p).0;
+    let (g, h) = hensel(f, &g, &h, p, k);
+    lift(&g, a, p, k, pk, out);
+    lift(&h, b, p, k, pk, out);
+}
+
+// The representative in (-m/2, m/2] of each coefficient.
+fn symmetric(a: Vec<Integer>, m: &Integer) -> Vec<Integer> {
+    let half = Integer::from(m / 2u32);
+    a.into_iter().map(|c| if c > half { c - m } else { c }).collect()
+}
+
+fn norm1(a: &[Integer]) -> Integer {
+    a.iter().map(|c| Integer::from(c.abs_ref())).sum()
+}
+
+// Steps through the subsets of 0..r of one size, as increasing indices.
+fn next_subset(s: &mut [usize], r: usize) -> bool {
+    let n = s.len();
+    for i in (0..n).rev() {
+        if s[i] < r - n + i {
+            s[i] += 1;
+            for j in i + 1..n {
+                s[j] = s[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+// Checks that a squarefree polynomial over Q of degree at least 2, given
+//   lowest degree first, is irreducible.
+pub(crate) fn irreducible(m: &[Rational]) -> bool {
+    // Scale to a primitive integer polynomial with a positive leading
+    //   coefficient.
+    let l = m.iter().fold(Integer::from(1), |l, c| l.lcm(c.denom()));
+    let f: Vec<Integer> = m.iter().map(|c| Rational::from(c * &l).into_numer_denom().0).collect();
+    let g = f.iter().fold(Integer::new(), |g, c| g.gcd(c));
+    let g = if f[f.len() - 1] < 0 { -g } else { g };
+    let f: Vec<Integer> = f.into_iter().map(|c| c.div_exact(&g)).collect();
+    let n = f.len() - 1;
+    let lc = f[n].clone();
+
+    // Any factor over Z, scaled to have leading coefficient lc, has a
+    //   1-norm times that of its cofactor below this bound.
+    let a = f.iter().map(|c| Integer::from(c.abs_ref())).max().unwrap_or_default();
+    let bound = Integer::from(n + 1) * (Integer::from(1) << n as u32) * a * &lc;
+
+    // Try a few primes that keep the degree and squarefreeness, and keep
+    //   the one with the fewest factors, since recombination is
+    //   exponential in their number.
+    let mut best: Option<(u64, Vec<Vec<Zp>>)> = None;
+    let mut found = 0;
+    let mut p = 1;
+    while found < 3 {
+        p += 2;
+        if !is_prime(p) || lc.mod_u(p as u32) == 0 {
+            continue;
+        }
+        let fp = dense::monic(&to_fp(&f, p), &p);
+        let df = dense::trim(fp.iter().enumerate().skip(1).map(|(i, c)| c.mul(&Zp::new(i as i64, p))).collect());
+        if dense::gcd(&fp, &df, &p).len() != 1 {
+            continue;
+        }
+        found += 1;
+        let factors = factor_mod(&fp, p);
+        if factors.len() == 1 {
+            return true;
+        }
+        if best.as_ref().is_none_or(|(_, b)| factors.len() < b.len()) {
+            best = Some((p, factors));
+        }
+    }
+    let (p, factors) = match best {
+        Some(b) => b,
+        None => unreachable!(),
+    };
+
+    let mut k = 1;
+    let mut pk = Integer::from(p);
+    while pk <= Integer::from(&bound * 2u32) {
+        pk *= p;
+        k += 1;
+    }
+    let mut lifted = Vec::new();
+    lift(&reduce(&f, &pk), &factors, p, k, &pk, &mut lifted);
+
+    // A subset of the lifted factors times lc, and its complement times
+    //   lc, multiply to lc f mod p^k. When their norms are small enough,
+    //   they do so over Z as well, giving a factorization of f.
+    let r = lifted.len();
+    let lcz = [lc];
+    for size in 1..=r / 2 {
+        let mut s: Vec<usize> = (0..size).collect();
+        loop {
+            let (mut g, mut h) = (lcz.to_vec(), lcz.to_vec());
+            for (i, x) in lifted.iter().enumerate() {
+                if s.contains(&i) {
+                    g = mul_z(&g, x, &pk);
+                } else {
+                    h = mul_z(&h, x, &pk);
+                }
+            }
+            let (g, h) = (symmetric(g, &pk), symmetric(h, &pk));
+            if norm1(&g) * norm1(&h) <= bound {
+                return false;
+            }
+            if !next_subset(&mut s, r) {
+                break;
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,369 @@
+extern crate rug;
+
+use std::fmt::{Debug, Display};
+use rug::{Integer, Rational};
+
+use super::galois::signed_terms;
+
+// Coefficient fields for polynomials.
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldError {
+    NotPrime,
+    InvalidModulus,
+    InvalidGenerator,
+    InvalidParameters,
+    Reducible,
+}
+
+// The arithmetic a coefficient domain has to provide. Elements carry
+//   everything they need to combine with each other, while anything needed
+//   to create elements from nothing (a modulus, a minimal polynomial, ...)
+//   lives in the domain, which every ring stores.
+pub trait Coefficient: Clone + PartialEq + Eq + Debug + Display {
+    type Domain: Clone + PartialEq + Eq + Debug;
+
+    fn zero(d: &Self::Domain) -> Self;
+    fn one(d: &Self::Domain) -> Self;
+    fn parse(s: &str, d: &Self::Domain) -> Option<Self>;
+
+    fn is_zero(&self) -> bool;
+    fn add(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+    fn neg(&self) -> Self;
+
+    // The image of an integer, found by doubling and adding one. Domains
+    //   with a direct conversion override this.
+    fn from_integer(n: &Integer, d: &Self::Domain) -> Self {
+        let one = Self::one(d);
+        let a = Integer::from(n.abs_ref());
+        let mut r = Self::zero(d);
+        for i in (0..a.significant_bits()).rev() {
+            r = r.add(&r);
+            if a.get_bit(i) {
+                r = r.add(&one);
+            }
+        }
+        if *n < 0 { r.neg() } else { r }
+    }
+}
+
+// Coefficients that can also be divided, which polynomial division and
+//   Grobner bases rely on. Fields only have to provide inverses. Dividing
+//   by zero gives None from the checked methods, and panics in `div`.
+pub trait Field: Coefficient {
+    fn checked_inv(&self) -> Option<Self>;
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        Some(self.mul(&other.checked_inv()?))
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        match self.checked_div(other) {
+            Some(c) => c,
+            None => panic!("Division by zero"),
+        }
+    }
+}
+
+impl Coefficient for Integer {
+    type Domain = ();
+
+    fn zero(_: &()) -> Self {
+        Integer::new()
+    }
+    fn one(_: &()) -> Self {
+        Integer::from(1)
+    }
+    fn parse(s: &str, _: &()) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn add(&self, other: &Self) -> Self {
+        Integer::from(self + other)
+    }
+    fn sub(&self, other: &Self) -> Self {
+        Integer::from(self - other)
+    }
+    fn mul(&self, other: &Self) -> Self {
+        Integer::from(self * other)
+    }
+    fn neg(&self) -> Self {
+        Integer::from(-self)
+    }
+    fn from_integer(n: &Integer, _: &()) -> Self {
+        n.clone()
+    }
+}
+
+impl Coefficient for Rational {
+    type Domain = ();
+
+    fn zero(_: &()) -> Self {
+        Rational::new()
+    }
+    fn one(_: &()) -> Self {
+        Rational::from(1)
+    }
+    fn parse(s: &str, _: &()) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn add(&self, other: &Self) -> Self {
+        Rational::from(self + other)
+    }
+    fn sub(&self, other: &Self) -> Self {
+        Rational::from(self - other)
+    }
+    fn mul(&self, other: &Self) -> Self {
+        Rational::from(self * other)
+    }
+    fn neg(&self) -> Self {
+        Rational::from(-self)
+    }
+    fn from_integer(n: &Integer, _: &()) -> Self {
+        Rational::from(n)
+    }
+}
+
+impl Field for Rational {
+    fn checked_inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(Rational::from(self.recip_ref()))
+        }
+    }
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            None
+        } else {
+            Some(Rational::from(self / other))
+        }
+    }
+}
+
+// Integers modulo a prime p below 2^32, so that products of two residues
+//   fit in a single machine word. The field's domain is the modulus.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Zp {
+    value: u64,
+    p: u64,
+}
+
+// Checks a modulus for use with `Zp` by trial division.
+pub fn is_prime(p: u64) -> bool {
+    if p < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d * d <= p {
+        if p.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+impl Zp {
+    pub fn new(n: i64, p: u64) -> Zp {
+        Zp { value: n.rem_euclid(p as i64) as u64, p }
+    }
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+    pub fn modulus(&self) -> u64 {
+        self.p
+    }
+}
+
+impl Display for Zp {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Coefficient for Zp {
+    type Domain = u64;
+
+    fn zero(p: &u64) -> Self {
+        Zp { value: 0, p: *p }
+    }
+    fn one(p: &u64) -> Self {
+        Zp { value: 1 % *p, p: *p }
+    }
+    // Accepts integers and fractions, reducing both parts mod p.
+    fn parse(s: &str, p: &u64) -> Option<Self> {
+        let r: Rational = s.parse().ok()?;
+        let n = Zp { value: r.numer().mod_u(*p as u32) as u64, p: *p };
+        let d = Zp { value: r.denom().mod_u(*p as u32) as u64, p: *p };
+        n.checked_div(&d)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+    fn add(&self, other: &Self) -> Self {
+        Zp { value: (self.value + other.value) % self.p, p: self.p }
+    }
+    fn sub(&self, other: &Self) -> Self {
+        Zp { value: (self.value + self.p - other.value) % self.p, p: self.p }
+    }
+    fn mul(&self, other: &Self) -> Self {
+        Zp { value: (self.value * other.value) % self.p, p: self.p }
+    }
+    fn neg(&self) -> Self {
+        Zp { value: (self.p - self.value) % self.p, p: self.p }
+    }
+    fn from_integer(n: &Integer, p: &u64) -> Self {
+        Zp { value: n.mod_u(*p as u32) as u64, p: *p }
+    }
+}
+
+impl Field for Zp {
+    // Multiplicative inverse from the extended Euclidean algorithm.
+    fn checked_inv(&self) -> Option<Self> {
+        if self.value == 0 {
+            return None;
+        }
+
+        let (mut r0, mut r1) = (self.p as i64, self.value as i64);
+        let (mut t0, mut t1) = (0i64, 1i64);
+        while r1 != 0 {
+            let q = r0 / r1;
+            let r = r0 - q * r1;
+            r0 = r1;
+            r1 = r;
+            let t = t0 - q * t1;
+            t0 = t1;
+            t1 = t;
+        }
+        Some(Zp::new(t0, self.p))
+    }
+}
+
+// Gaussian rationals a + bi, for complex data with rational real and
+//   imaginary parts.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GaussianRational {
+    pub re: Rational,
+    pub im: Rational,
+}
+
+impl GaussianRational {
+    pub fn new(re: Rational, im: Rational) -> GaussianRational {
+        GaussianRational { re, im }
+    }
+
+    pub fn i() -> GaussianRational {
+        GaussianRational { re: Rational::new(), im: Rational::from(1) }
+    }
+
+    pub fn conj(&self) -> GaussianRational {
+        GaussianRational { re: self.re.clone(), im: Rational::from(-&self.im) }
+    }
+
+    // Squared absolute value a^2 + b^2.
+    pub fn norm(&self) -> Rational {
+        Rational::from(&self.re * &self.re) + Rational::from(&self.im * &self.im)
+    }
+}
+
+// Real numbers print as plain rationals, and anything else in parentheses
+//   like `(3/2+1/4i)`, which `Polynomial::from_string` reads back.
+impl Display for GaussianRational {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.im == 0 {
+            return write!(f, "{}", self.re);
+        }
+
+        let mut s = String::from("(");
+        if self.re != 0 {
+            s += &self.re.to_string();
+            if self.im > 0 {
+                s += "+";
+            }
+        }
+        if self.im == -1 {
+            s += "-";
+        } else if self.im != 1 {
+            s += &self.im.to_string();
+        }
+        s += "i)";
+
+        write!(f, "{}", s)
+    }
+}
+
+impl Coefficient for GaussianRational {
+    type Domain = ();
+
+    fn zero(_: &()) -> Self {
+        GaussianRational { re: Rational::new(), im: Rational::new() }
+    }
+    fn one(_: &()) -> Self {
+        GaussianRational { re: Rational::from(1), im: Rational::new() }
+    }
+    // Accepts sums of rationals and rational multiples of i, such as
+    //   `3/2 + 1/4i`, `-i` or `2*i - 1`.
+    fn parse(s: &str, _: &()) -> Option<Self> {
+        let mut z = GaussianRational::zero(&());
+
+        for (sign, t) in signed_terms(s) {
+            let (c, imaginary) = match t.strip_suffix('i') {
+                Some(c) => (c.trim().trim_end_matches('*').trim(), true),
+                None => (t, false),
+            };
+            let c = match c {
+                "" if imaginary => Rational::from(1),
+                _ => c.parse::<Rational>().ok()?,
+            };
+            let c = if sign { -c } else { c };
+
+            if imaginary {
+                z.im += c;
+            } else {
+                z.re += c;
+            }
+        }
+
+        Some(z)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0 && self.im == 0
+    }
+    fn add(&self, other: &Self) -> Self {
+        GaussianRational { re: Rational::from(&self.re + &other.re), im: Rational::from(&self.im + &other.im) }
+    }
+    fn sub(&self, other: &Self) -> Self {
+        GaussianRational { re: Rational::from(&self.re - &other.re), im: Rational::from(&self.im - &other.im) }
+    }
+    fn mul(&self, other: &Self) -> Self {
+        let re = Rational::from(&self.re * &other.re) - Rational::from(&self.im * &other.im);
+        let im = Rational::from(&self.re * &other.im) + Rational::from(&self.im * &other.re);
+        GaussianRational { re, im }
+    }
+    fn neg(&self) -> Self {
+        GaussianRational { re: Rational::from(-&self.re), im: Rational::from(-&self.im) }
+    }
+}
+
+impl Field for GaussianRational {
+    // The conjugate divided by the norm.
+    fn checked_inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let n = self.norm();
+        Some(GaussianRational { re: Rational::from(&self.re / &n), im: Rational::from(-&self.im) / n })
+    }
+}
@@ -0,0 +1,254 @@
+extern crate rug;
+
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use rug::Integer;
+
+use super::dense;
+use super::fields::*;
+
+// Finite fields GF(p^k), represented as GF(p)[a] / (m(a)) for a monic
+//   irreducible modulus m of degree k.
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GfContext {
+    pub p: u64,
+    // Coefficients of the monic modulus, lowest degree first.
+    pub modulus: Vec<Zp>,
+    pub generator: String,
+}
+
+// An element of GF(p^k) as a polynomial in the generator of degree
+//   below k, lowest degree first and with no trailing zeros.
+#[derive(Clone, Debug)]
+pub struct Gf {
+    coeffs: Vec<Zp>,
+    field: Arc<GfContext>,
+}
+
+fn residues(v: &[u64], p: u64) -> Vec<Zp> {
+    dense::trim(v.iter().map(|c| Zp::new((c % p) as i64, p)).collect())
+}
+
+// Checks that a monic polynomial of degree k is irreducible over GF(p)
+//   with Ben-Or's test: it has no factor of degree i <= k/2 exactly when
+//   gcd(m, a^(p^i) - a) = 1 for each such i.
+fn irreducible(m: &[Zp], p: u64) -> bool {
+    let k = m.len() - 1;
+    let a = residues(&[0, 1], p);
+    let mut x = a.clone();
+
+    for _ in 0..k / 2 {
+        x = dense::pow_mod(&x, &Integer::from(p), m, &p);
+        if dense::gcd(m, &dense::sub(&x, &a), &p).len() != 1 {
+            return false;
+        }
+    }
+    true
+}
+
+impl GfContext {
+    // Builds GF(p^k) from a modulus given lowest degree first. The modulus
+    //   is made monic, and must be irreducible of degree at least 1.
+    pub fn new(p: u64, modulus: Vec<u64>, generator: &str) -> Result<Arc<GfContext>, FieldError> {
+        if p > u64::from(u32::MAX) || !is_prime(p) {
+            return Err(FieldError::NotPrime);
+        }
+        if generator.is_empty() || !generator.chars().all(|c| c.is_alphabetic()) {
+            return Err(FieldError::InvalidGenerator);
+        }
+
+        let m = residues(&modulus, p);
+        if m.len() < 2 {
+            return Err(FieldError::InvalidModulus);
+        }
+
+        let m = dense::monic(&m, &p);
+        if !irreducible(&m, p) {
+            return Err(FieldError::Reducible);
+        }
+
+        Ok(Arc::new(GfContext { p, modulus: m, generator: generator.to_string() }))
+    }
+
+    // Number of elements in the field.
+    pub fn order(&self) -> u128 {
+        u128::from(self.p).pow(self.degree() as u32)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.modulus.len() - 1
+    }
+
+    // Parses a polynomial in the generator, such as `a^2 + 2a + 1` or
+    //   `3*a - 1/2`, with each coefficient read mod p.
+    pub fn parse_poly(&self, s: &str) -> Option<Vec<u64>> {
+        let mut v = Vec::new();
+
+        for (sign, t) in signed_terms(s) {
+            if t.is_empty() {
+                return None;
+            }
+            let (c, e) = match t.find(self.generator.as_str()) {
+                Some(i) => {
+                    let (c, rest) = t.split_at(i);
+                    let c = c.trim().trim_end_matches('*').trim();
+                    let rest = &rest[self.generator.len()..];
+                    let e = match rest.trim().strip_prefix('^') {
+                        Some(e) => e.trim().parse::<usize>().ok()?,
+                        None if rest.trim().is_empty() => 1,
+                        None => return None,
+                    };
+                    (c, e)
+                },
+                None => (t, 0),
+            };
+            let c = if c.is_empty() {
+                Zp::one(&self.p)
+            } else {
+                Zp::parse(c, &self.p)?
+            };
+            let c = if sign { c.neg() } else { c };
+
+            if v.len() <= e {
+                v.resize(e + 1, Zp::zero(&self.p));
+            }
+            v[e] = v[e].add(&c);
+        }
+
+        Some(dense::trim(v).iter().map(|c| c.value()).collect())
+    }
+}
+
+// Splits a sum such as `a^2 - 2a + 1` into its terms, each with a flag
+//   that is set when the term is subtracted.
+pub(crate) fn signed_terms(s: &str) -> Vec<(bool, &str)> {
+    let mut terms = Vec::new();
+    let mut neg = false;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        if c != '+' && c != '-' {
+            continue;
+        }
+        let t = s[start..i].trim();
+        if t.is_empty() {
+            neg ^= c == '-';
+        } else if t.ends_with(['^', '*', '/']) {
+            continue;
+        } else {
+            terms.push((neg, t));
+            neg = c == '-';
+        }
+        start = i + 1;
+    }
+    terms.push((neg, s[start..].trim()));
+
+    terms
+}
+
+impl Gf {
+    pub fn context(&self) -> &Arc<GfContext> {
+        &self.field
+    }
+
+    // The class of a in GF(p)[a] / (m(a)).
+    pub fn generator(field: &Arc<GfContext>) -> Gf {
+        Gf::from_coeffs(vec![0, 1], field)
+    }
+
+    pub fn from_coeffs(coeffs: Vec<u64>, field: &Arc<GfContext>) -> Gf {
+        Gf::reduced(residues(&coeffs, field.p), field)
+    }
+
+    fn reduced(v: Vec<Zp>, field: &Arc<GfContext>) -> Gf {
+        Gf { coeffs: dense::divmod_monic(&v, &field.modulus, &field.p).1, field: Arc::clone(field) }
+    }
+
+    pub fn coeffs(&self) -> Vec<u64> {
+        self.coeffs.iter().map(|c| c.value()).collect()
+    }
+}
+
+impl PartialEq for Gf {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs &&
+            (Arc::ptr_eq(&self.field, &other.field) || self.field == other.field)
+    }
+}
+
+impl Eq for Gf {}
+
+impl Hash for Gf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coeffs.hash(state);
+    }
+}
+
+// Constants print as plain residues, and anything else as a parenthesized
+//   polynomial in the generator.
+impl Display for Gf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coeffs.len() <= 1 {
+            return write!(f, "{}", self.coeffs.first().map_or(0, |c| c.value()));
+        }
+
+        let a = &self.field.generator;
+        let mut terms = Vec::new();
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let c = if c.value() == 1 && i > 0 { String::new() } else { c.to_string() };
+            terms.push(match i {
+                0 => c,
+                1 => format!("{}{}", c, a),
+                _ => format!("{}{}^{}", c, a, i),
+            });
+        }
+        write!(f, "({})", terms.join("+"))
+    }
+}
+
+impl Coefficient for Gf {
+    type Domain = Arc<GfContext>;
+
+    fn zero(d: &Arc<GfContext>) -> Self {
+        Gf { coeffs: Vec::new(), field: Arc::clone(d) }
+    }
+    fn one(d: &Arc<GfContext>) -> Self {
+        Gf { coeffs: vec![Zp::one(&d.p)], field: Arc::clone(d) }
+    }
+    fn parse(s: &str, d: &Arc<GfContext>) -> Option<Self> {
+        Some(Gf::from_coeffs(d.parse_poly(s)?, d))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+    fn add(&self, other: &Self) -> Self {
+        Gf { coeffs: dense::add(&self.coeffs, &other.coeffs), field: Arc::clone(&self.field) }
+    }
+    fn sub(&self, other: &Self) -> Self {
+        Gf { coeffs: dense::sub(&self.coeffs, &other.coeffs), field: Arc::clone(&self.field) }
+    }
+    fn mul(&self, other: &Self) -> Self {
+        Gf::reduced(dense::mul(&self.coeffs, &other.coeffs, &self.field.p), &self.field)
+    }
+    fn neg(&self) -> Self {
+        Gf { coeffs: dense::neg(&self.coeffs), field: Arc::clone(&self.field) }
+    }
+}
+
+impl Field for Gf {
+    // The modulus is irreducible, so a nonzero element c has gcd 1 with it,
+    //   and the t with s m + t c = 1 is the inverse of c.
+    fn checked_inv(&self) -> Option<Self> {
+        if self.coeffs.is_empty() {
+            return None;
+        }
+        let (_, _, t) = dense::ext_gcd(&self.field.modulus, &self.coeffs, &self.field.p);
+        Some(Gf::reduced(t, &self.field))
+    }
+}
@@ -0,0 +1,174 @@
+use super::error::PolyError;
+use super::fields::Coefficient;
+use super::operations::{mult_monoms, same_ring};
+use super::polynomials::*;
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+// Geobuckets, an accumulator for polynomials that are repeatedly updated
+//   by adding multiples of other polynomials, as in division.
+//
+// Terms are spread over buckets whose sizes grow geometrically, and a
+//   polynomial added to the accumulator is only merged with a bucket of
+//   about its own size. When a bucket outgrows its size it is merged into
+//   the next one. Adding a polynomial of length m to an accumulator of
+//   length n then costs about m log n rather than n + m, and the leading
+//   term is found by looking at the head of each bucket.
+
+// Bucket i holds up to 4^(i + 1) terms.
+const BASE: usize = 4;
+
+// Each bucket keeps its terms in increasing order, so the leading term
+//   can be popped from the end.
+pub struct Geobucket<F: Coefficient> {
+    buckets: Vec<Vec<Monomial<F>>>,
+    ring: Arc<Ring<F>>,
+}
+
+fn capacity(i: usize) -> usize {
+    BASE.pow(i as u32 + 1)
+}
+
+// Merges two increasing lists of terms, adding the coefficients of equal
+//   monomials and dropping those that cancel.
+fn merge<F: Coefficient>(a: Vec<Monomial<F>>, b: Vec<Monomial<F>>) -> Vec<Monomial<F>> {
+    let mut v = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+
+    loop {
+        let ord = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => break,
+        };
+        match ord {
+            Ordering::Less => v.extend(a.next()),
+            Ordering::Greater => v.extend(b.next()),
+            Ordering::Equal => {
+                if let (Some(mut x), Some(y)) = (a.next(), b.next()) {
+                    x.coefficient = x.coefficient.add(&y.coefficient);
+                    if !x.coefficient.is_zero() {
+                        v.push(x);
+                    }
+                }
+            },
+        }
+    }
+    v
+}
+
+impl<F: Coefficient> Geobucket<F> {
+    pub fn new(ring: &Arc<Ring<F>>) -> Self {
+        Geobucket { buckets: Vec::new(), ring: Arc::clone(ring) }
+    }
+
+    pub fn from_poly(f: &Polynomial<F>) -> Self {
+        let mut b = Geobucket::new(&f.ring);
+        if !f.terms.is_empty() {
+            b.add_terms(f.terms.iter().rev().cloned().collect());
+        }
+        b
+    }
+
+    // Adds a list of distinct terms, sorting them first unless they are
+    //   already in increasing order.
+    fn add_terms(&mut self, mut terms: Vec<Monomial<F>>) {
+        if terms.windows(2).any(|w| w[0] > w[1]) {
+            terms.sort();
+        }
+
+        let mut i = 0;
+        while capacity(i) < terms.len() {
+            i += 1;
+        }
+
+        loop {
+            if self.buckets.len() <= i {
+                self.buckets.resize_with(i + 1, Vec::new);
+            }
+            let b = std::mem::take(&mut self.buckets[i]);
+            terms = merge(b, terms);
+            if terms.len() <= capacity(i) {
+                self.buckets[i] = terms;
+                return;
+            }
+            i += 1;
+        }
+    }
+
+    pub fn add(&mut self, f: &Polynomial<F>) -> Result<(), PolyError> {
+        same_ring(&self.ring, &f.ring)?;
+        if !f.terms.is_empty() {
+            self.add_terms(f.terms.iter().rev().cloned().collect());
+        }
+        Ok(())
+    }
+
+    // Subtracts t * g, where g is a list of terms, usually in decreasing
+    //   order.
+    pub fn sub_mult(&mut self, t: &Monomial<F>, g: &[Monomial<F>]) -> Result<(), PolyError> {
+        same_ring(&self.ring, &t.ring)?;
+        if g.is_empty() || t.coefficient.is_zero() {
+            return Ok(());
+        }
+        let mut t = t.clone();
+        t.coefficient = t.coefficient.neg();
+        let terms: Result<Vec<Monomial<F>>, PolyError> = g.iter().rev().map(|m| mult_monoms(&t, m)).collect();
+        self.add_terms(terms?);
+        Ok(())
+    }
+
+    // Finds the leading term, first collecting all the terms of that
+    //   monomial from every bucket into the first one.
+    pub fn lead(&mut self) -> Option<&Monomial<F>> {
+        loop {
+            let mut top: Option<usize> = None;
+            for (i, b) in self.buckets.iter().enumerate() {
+                if let Some(m) = b.last() {
+                    match top {
+                        Some(j) if self.buckets[j].last().is_some_and(|t| t >= m) => (),
+                        _ => top = Some(i),
+                    }
+                }
+            }
+            let j = top?;
+
+            let mut m = self.buckets[j].pop()?;
+            for (i, b) in self.buckets.iter_mut().enumerate() {
+                if i != j && b.last().is_some_and(|t| t.degree == m.degree) {
+                    if let Some(t) = b.pop() {
+                        m.coefficient = m.coefficient.add(&t.coefficient);
+                    }
+                }
+            }
+
+            if !m.coefficient.is_zero() {
+                // Anything left in the first bucket is smaller than m.
+                if self.buckets.is_empty() {
+                    self.buckets.push(Vec::new());
+                }
+                self.buckets[0].push(m);
+                return self.buckets[0].last();
+            }
+        }
+    }
+
+    pub fn pop_lead(&mut self) -> Option<Monomial<F>> {
+        self.lead()?;
+        self.buckets[0].pop()
+    }
+
+    pub fn is_zero(&mut self) -> bool {
+        self.lead().is_none()
+    }
+
+    // Merges the buckets back into a single polynomial.
+    pub fn into_poly(self) -> Polynomial<F> {
+        let mut terms = self.buckets.into_iter().fold(Vec::new(), merge);
+        terms.reverse();
+        Polynomial { length: terms.len(), terms, ring: self.ring }
+    }
+}
@@ -0,0 +1,179 @@
+extern crate rug;
+
+use super::error::PolyError;
+use super::polynomials::*;
+use super::operations::*;
+
+use std::sync::Arc;
+use rug::Integer;
+
+// Grobner bases for ideals of polynomials with integer coefficients.
+//
+// Over Z one leading term can only cancel another when its coefficient
+//   divides the other's, so the bases computed here are strong: every
+//   leading term of the ideal is divisible by the leading term of one of
+//   the basis elements. Buchberger's algorithm then needs GCD-polynomials
+//   on top of S-polynomials, to produce leading terms whose coefficients
+//   are the gcds of existing ones.
+
+// Multiplies a polynomial by the term c * m / d.
+fn mult_term(f: &Polynomial<Integer>, c: &Integer, m: &Monomial<Integer>, d: &Monomial<Integer>) -> Result<Polynomial<Integer>, PolyError> {
+    let t = Monomial {
+        coefficient: c.clone(),
+        degree: m.degree.checked_div(&d.degree)?,
+        ring: Arc::clone(&f.ring),
+    };
+    mult_polys(&Polynomial::from_monom(t), f)
+}
+
+// Reduces a polynomial by a set of integer polynomials. Each term is
+//   reduced by any element whose leading monomial divides it, replacing
+//   its coefficient with the remainder mod that element's leading
+//   coefficient, and terms that can't be reduced further are moved into
+//   the result.
+//
+//   Against a strong Grobner basis the result is zero exactly when the
+//   polynomial is in the ideal.
+pub fn normal_form(f: &Polynomial<Integer>, g: &PolySet<Integer>) -> Result<Polynomial<Integer>, PolyError> {
+    for h in g.0.iter() {
+        same_ring(&f.ring, &h.ring)?;
+    }
+
+    let mut r = Polynomial::zero(&f.ring);
+    let mut p = f.clone();
+
+    while !p.terms.is_empty() {
+        let mut div = false;
+        for h in g.0.iter().filter(|h| !h.terms.is_empty()) {
+            let lt = &p.terms[0];
+            let lh = &h.terms[0];
+            if monom_divides(lh, lt) {
+                let (q, _) = lt.coefficient.clone().div_rem_euc(lh.coefficient.clone());
+                if q != 0 {
+                    p = sub_polys(&p, &mult_term(h, &q, lt, lh)?)?;
+                    div = true;
+                    break;
+                }
+            }
+        }
+        if !div {
+            let lt = Polynomial::from_monom(p.terms.remove(0));
+            p.length -= 1;
+            r = add_polys(&r, &lt)?;
+        }
+    }
+
+    Ok(r)
+}
+
+// S-polynomial over Z, scaling both leading terms up to the lcm of
+//   their coefficients and monomials.
+pub fn s_poly_z(f: &Polynomial<Integer>, g: &Polynomial<Integer>) -> Result<Polynomial<Integer>, PolyError> {
+    let (m1, m2) = match (f.terms.first(), g.terms.first()) {
+        (Some(m1), Some(m2)) => (m1, m2),
+        (None, _) => return Ok(scalar_mult(g, Integer::from(-1))),
+        (_, None) => return Ok(f.clone()),
+    };
+    let m = lcm(m1, m2)?;
+    let l = m1.coefficient.clone().lcm(&m2.coefficient);
+
+    let a = Integer::from(&l / &m1.coefficient);
+    let b = Integer::from(&l / &m2.coefficient);
+
+    sub_polys(&mult_term(f, &a, &m, m1)?, &mult_term(g, &b, &m, m2)?)
+}
+
+// GCD-polynomial s * (m / m1) * f + t * (m / m2) * g, where s and t are
+//   the Bezout cofactors of the leading coefficients, so that its leading
+//   term is their gcd times the lcm of the leading monomials.
+//
+//   Returns None when one leading coefficient divides the other, since
+//   the S-polynomial already covers that case, or when either polynomial
+//   is zero.
+pub fn gcd_poly(f: &Polynomial<Integer>, g: &Polynomial<Integer>) -> Result<Option<Polynomial<Integer>>, PolyError> {
+    let (m1, m2) = match (f.terms.first(), g.terms.first()) {
+        (Some(m1), Some(m2)) => (m1, m2),
+        _ => return Ok(None),
+    };
+
+    if m1.coefficient.is_divisible(&m2.coefficient) || m2.coefficient.is_divisible(&m1.coefficient) {
+        return Ok(None);
+    }
+
+    let m = lcm(m1, m2)?;
+    let (_, s, t) = m1.coefficient.clone().gcd_cofactors(m2.coefficient.clone(), Integer::new());
+
+    Ok(Some(add_polys(&mult_term(f, &s, &m, m1)?, &mult_term(g, &t, &m, m2)?)?))
+}
+
+// Calculates a reduced strong Grobner basis over Z for the ideal
+//   generated by the given integer polynomials.
+//
+// @pre The polynomials are normalized.
+pub fn strong_grobner_basis(ps: &PolySet<Integer>) -> Result<PolySet<Integer>, PolyError> {
+    same_rings(ps)?;
+
+    let mut s = ps.0.clone();
+    let mut g = PolySet(Vec::new());
+
+    while let Some(f) = s.pop() {
+        let r = normal_form(&f, &g)?;
+        if !r.terms.is_empty() {
+            for p in g.0.iter() {
+                s.push(s_poly_z(&r, p)?);
+                if let Some(h) = gcd_poly(&r, p)? {
+                    s.push(h);
+                }
+            }
+            g.0.push(r);
+        }
+    }
+    reduce_z(g)
+}
+
+// Reduces a strong Grobner basis: leading coefficients are made positive,
+//   elements whose leading term is divisible by another's are dropped,
+//   and the rest are reduced by each other.
+pub fn reduce_z(g: PolySet<Integer>) -> Result<PolySet<Integer>, PolyError> {
+    let mut g: Vec<Polynomial<Integer>> = g.0.into_iter()
+        .filter(|p| !p.terms.is_empty())
+        .map(|p| {
+            if p.terms[0].coefficient < 0 {
+                scalar_mult(&p, Integer::from(-1))
+            } else {
+                p
+            }
+        })
+        .collect();
+
+    let mut i = 0;
+    while i < g.len() {
+        let redundant = (0..g.len()).any(|j| {
+            let (a, b) = (&g[j].terms[0], &g[i].terms[0]);
+            j != i && monom_divides(a, b) && b.coefficient.is_divisible(&a.coefficient) &&
+                (j < i || a.degree != b.degree || a.coefficient != b.coefficient)
+        });
+        if redundant {
+            g.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut g = PolySet(g);
+    let mut gp = Vec::new();
+
+    for i in 0..g.0.len() {
+        let p = g.0.remove(i);
+        gp.push(normal_form(&p, &g)?);
+        g.0.insert(i, p);
+    }
+    Ok(PolySet(gp))
+}
+
+// Tests whether a polynomial lies in the ideal over Z generated by the
+//   given polynomials.
+pub fn in_ideal(f: &Polynomial<Integer>, ideal: &PolySet<Integer>) -> Result<bool, PolyError> {
+    let g = strong_grobner_basis(ideal)?;
+    Ok(normal_form(f, &g)?.terms.is_empty())
+}
@@ -1,2 +1,18 @@
+pub mod arithmetic;
+mod dense;
+pub mod error;
+pub mod exponents;
+mod factorization;
+pub mod fields;
+pub mod galois;
+pub mod geobucket;
+pub mod integers;
+pub mod modular;
+pub mod number_fields;
 pub mod polynomials;
+pub mod rational_functions;
+pub mod univariate;
 pub mod operations;
+pub mod parser;
+#[cfg(feature = "serde")]
+mod serialization;
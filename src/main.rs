@@ -1,10 +1,18 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::fields::*;
+use polynomial_operations::galois::*;
+use polynomial_operations::number_fields::*;
+use polynomial_operations::parser;
 use polynomial_operations::polynomials::*;
 use polynomial_operations::operations::*;
+use polynomial_operations::rational_functions::*;
 
 use std::io;
 use std::io::prelude::*;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::fmt;
+use std::sync::Arc;
+use rug::Rational;
 
 #[derive(Debug)]
 enum ParseError {
@@ -12,38 +20,52 @@ enum ParseError {
     ArgumentError,
     SyntaxError,
     RingError,
+    OrderingError,
+    FieldError,
+    OperationError(PolyError),
+    PolynomialError(parser::ParseError),
+}
+
+impl From<PolyError> for ParseError {
+    fn from(e: PolyError) -> Self {
+        ParseError::OperationError(e)
+    }
+}
+
+impl From<parser::ParseError> for ParseError {
+    fn from(e: parser::ParseError) -> Self {
+        ParseError::PolynomialError(e)
+    }
 }
 
 #[derive(Clone)]
-enum Item {
-    P(Polynomial),
-    Qr((Polynomial, Polynomial)),
-    Ps(PolySet),
-    Qsr((PolySet, Polynomial)),
-    OK,
+enum Item<F: Field = Rational> {
+    P(Polynomial<F>),
+    Qr((Polynomial<F>, Polynomial<F>)),
+    Ps(PolySet<F>),
+    Qsr((PolySet<F>, Polynomial<F>)),
 }
 
-impl ToString for Item {
-    fn to_string(&self) -> String {
+impl<F: Field> fmt::Display for Item<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
-            Self::P(p1) => p1.to_string(),
-            Self::Qr((q, r)) => format!("q: {}, r: {}", q.to_string(), r.to_string()),
-            Self::Ps(ps) => ps.to_string(),
-            Self::Qsr((ps, r)) => format!("qs: {}, r: {}", ps.to_string(), r.to_string()),
-            Self::OK => String::new(),
+            Self::P(p1) => write!(f, "{}", p1),
+            Self::Qr((q, r)) => write!(f, "q: {}, r: {}", q, r),
+            Self::Ps(ps) => write!(f, "{}", ps),
+            Self::Qsr((ps, r)) => write!(f, "qs: {}, r: {}", ps, r),
         }
     }
 }
 
-fn get_item(x: &str, ring: &Rc<Ring>, table: &HashMap<String, Item>) -> Result<Item, MonomError> {
+fn get_item<F: Field>(x: &str, ring: &Arc<Ring<F>>, table: &HashMap<String, Item<F>>) -> Result<Item<F>, ParseError> {
     match table.get(x) {
         Some(item) => Ok(item.clone()),
-        None => Ok(Item::P(Polynomial::from_string(x, ring)?)),
+        None => Ok(Item::P(Polynomial::parse(x, ring)?)),
     }
 }
 
 
-fn prep_ps(x: &str, ring: &Rc<Ring>, table: &HashMap<String, Item>) -> Result<Vec<Item>, ParseError> {
+fn prep_ps<F: Field>(x: &str, ring: &Arc<Ring<F>>, table: &HashMap<String, Item<F>>) -> Result<Vec<Item<F>>, ParseError> {
     let s: Vec<&str> = x.split(';').collect();
     if s.len() == 1 {
         x.split(' ')
@@ -60,46 +82,46 @@ fn prep_ps(x: &str, ring: &Rc<Ring>, table: &HashMap<String, Item>) -> Result<Ve
 
 
 
-fn parse_expression_h(x: &str, ring: &Rc<Ring>, table: &HashMap<String, Item>) -> Result<Item, ParseError> {
+fn parse_expression_h<F: Field>(x: &str, ring: &Arc<Ring<F>>, table: &HashMap<String, Item<F>>) -> Result<Item<F>, ParseError> {
     if let Some((op, s)) = x.split_once(' ') {
         if op == "+" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 2 {
                 return Err(ParseError::ArgumentError);
             }
             match (&ps[0], &ps[1]) {
-                (Item::P(p1), Item::P(p2)) => Ok(Item::P(add_polys(&p1, &p2))),
+                (Item::P(p1), Item::P(p2)) => Ok(Item::P(add_polys(&p1, &p2)?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else if op == "-" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 2 {
                 return Err(ParseError::ArgumentError);
             }
             match (&ps[0], &ps[1]) {
-                (Item::P(p1), Item::P(p2)) => Ok(Item::P(sub_polys(&p1, &p2))),
+                (Item::P(p1), Item::P(p2)) => Ok(Item::P(sub_polys(&p1, &p2)?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else if op == "*" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 2 {
                 return Err(ParseError::ArgumentError);
             }
             match (&ps[0], &ps[1]) {
-                (Item::P(p1), Item::P(p2)) => Ok(Item::P(mult_polys(&p1, &p2))),
+                (Item::P(p1), Item::P(p2)) => Ok(Item::P(mult_polys(&p1, &p2)?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else if op == "/" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 2 {
                 return Err(ParseError::ArgumentError);
             }
             match (&ps[0], &ps[1]) {
-                (Item::P(p1), Item::P(p2)) => Ok(Item::Qr(divide_polys(&p1, &p2))),
+                (Item::P(p1), Item::P(p2)) => Ok(Item::Qr(divide_polys(&p1, &p2)?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else if op == "s" {
-            let ps:Result<Vec<Polynomial>, ParseError> = prep_ps(s, ring, table)?
+            let ps:Result<Vec<Polynomial<F>>, ParseError> = prep_ps(s, ring, table)?
                 .iter()
                 .map(|x| {
                     match x {
@@ -110,83 +132,187 @@ fn parse_expression_h(x: &str, ring: &Rc<Ring>, table: &HashMap<String, Item>) -
                 .collect();
             Ok(Item::Ps(PolySet(ps?)))
         } else if op == "/s" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 2 {
                 return Err(ParseError::ArgumentError);
             }
             match (&ps[0], &ps[1]) {
-                (Item::P(p1), Item::Ps(polys)) => Ok(Item::Qsr(divide_poly_set(&p1, &mut polys.clone()))),
+                (Item::P(p1), Item::Ps(polys)) => Ok(Item::Qsr(divide_poly_set(&p1, &mut polys.clone())?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else if op == "base" {
-            let ps: Vec<Item> = prep_ps(s, ring, table)?;
+            let ps: Vec<Item<F>> = prep_ps(s, ring, table)?;
             if ps.len() < 1 {
                 return Err(ParseError::ArgumentError);
             }
             match &ps[0] {
-                Item::Ps(ps) => Ok(Item::Ps(grobner_basis(&ps))),
+                Item::Ps(ps) => Ok(Item::Ps(grobner_basis(&ps)?)),
                 _ => Err(ParseError::ArgumentError),
             }
         } else {
-            Err(ParseError::InvalidOperation)
+            // Anything else is a polynomial written with spaces, unless
+            //   it starts with a word that can't be read as one.
+            match get_item(x, ring, table) {
+                Err(_) if op.chars().all(char::is_alphabetic) && Polynomial::parse(op, ring).is_err() => {
+                    Err(ParseError::InvalidOperation)
+                },
+                item => item,
+            }
         }
     } else {
-        match get_item(x, ring, table) {
-            Ok(item) => Ok(item),
-            Err(_) => Err(ParseError::SyntaxError),
-        }
+        get_item(x, ring, table)
     }
 }
 
-fn parse_ring(x: &str) -> Result<Ring, ParseError> {
-    let s = match x.split_once('[') {
-        Some((_, a)) => a,
+// The ring currently in use, over whichever field it was declared with.
+enum AnyRing {
+    Rational(Arc<Ring>),
+    Modular(Arc<Ring<Zp>>),
+    Extension(Arc<Ring<Gf>>),
+    Parametric(Arc<Ring<RationalFunction>>),
+    Algebraic(Arc<Ring<AlgebraicNumber>>),
+}
+
+// Variables are kept separately for each coefficient field, since they
+//   can only be combined with polynomials over the same field.
+#[derive(Default)]
+struct Tables {
+    rational: HashMap<String, Item>,
+    modular: HashMap<String, Item<Zp>>,
+    extension: HashMap<String, Item<Gf>>,
+    parametric: HashMap<String, Item<RationalFunction>>,
+    algebraic: HashMap<String, Item<AlgebraicNumber>>,
+}
+
+// Rings are written as `[x, y, z] ord`, optionally with a weight
+//   vector before the ordering, as in `[x, y, z] w(1, 2, 3) lex`.
+//   The ordering after the weights is used to break ties.
+//
+//   A weight matrix can be given instead of an ordering, with rows
+//   separated by semicolons, as in `[x, y] m(1, 1; 0, -1)`, and block
+//   orderings are written `[t, x, y] block(1, lex, grevlex)`.
+//
+//   Polynomials can then be entered as `x^2 - 3/2*x*y + 1`.
+//
+//   Coefficients are rational unless the ring is prefixed with a
+//   prime field, as in `Z/32003[x, y, z] grevlex`, or with a finite
+//   field given by its characteristic and an irreducible modulus in
+//   the generator, as in `GF(2, a^3 + a + 1)[x, y] lex`. Elements of
+//   GF(p^k) are then written in parentheses, like `(a^2+1)x^1y^0`.
+//
+//   Symbolic parameters are declared as a rational function field, as
+//   in `Q(a, b)[x, y] lex`. Coefficients are then polynomials or
+//   quotients of them in the parameters, like `((a+1)/(b))x^1y^0`.
+//
+//   A number field is declared by the minimal polynomial of its
+//   generator instead, as in `Q(a^2 - 2)[x, y] grevlex` for Q(sqrt(2)),
+//   with elements written like `(1/2a-1)x^1y^0`.
+fn parse_ring(x: &str) -> Result<AnyRing, ParseError> {
+    let (field, s) = match x.split_once('[') {
+        Some(a) => a,
         None => return Err(ParseError::SyntaxError),
     };
-    let (symbs, ord) = match s.split_once(']') {
-        Some((a, b)) => (a, b),
-        None => return Err(ParseError::SyntaxError),
+    let ring: Ring = match format!("[{}", s).parse() {
+        Ok(r) => r,
+        Err(PolyError::Ordering(_)) => return Err(ParseError::OrderingError),
+        Err(_) => return Err(ParseError::SyntaxError),
     };
 
-    let vars = symbs.split(',').map(|x| x.trim().to_string()).collect();
+    if let Some(p) = field.trim().strip_prefix("Z/") {
+        let p = match p.trim().parse::<u64>() {
+            Ok(p) => p,
+            Err(_) => return Err(ParseError::SyntaxError),
+        };
+        if p > u64::from(u32::MAX) || !is_prime(p) {
+            return Err(ParseError::FieldError);
+        }
+        Ok(AnyRing::Modular(Arc::new(ring.over(p))))
+    } else if let Some(gf) = field.trim().strip_prefix("GF(") {
+        let (p, m) = match gf.strip_suffix(')').and_then(|a| a.split_once(',')) {
+            Some(a) => a,
+            None => return Err(ParseError::SyntaxError),
+        };
+        let p = match p.trim().parse::<u64>() {
+            Ok(p) => p,
+            Err(_) => return Err(ParseError::SyntaxError),
+        };
+        let generator: String = match m.find(|c: char| c.is_alphabetic()) {
+            Some(i) => m[i..].chars().take_while(|c| c.is_alphabetic()).collect(),
+            None => return Err(ParseError::FieldError),
+        };
+        let unchecked = GfContext { p, modulus: Vec::new(), generator: generator.clone() };
+        let modulus = match unchecked.parse_poly(m) {
+            Some(m) => m,
+            None => return Err(ParseError::SyntaxError),
+        };
+        match GfContext::new(p, modulus, &generator) {
+            Ok(gf) => Ok(AnyRing::Extension(Arc::new(ring.over(gf)))),
+            Err(_) => Err(ParseError::FieldError),
+        }
+    } else if let Some(q) = field.trim().strip_prefix("Q(") {
+        let q = match q.strip_suffix(')') {
+            Some(a) => a,
+            None => return Err(ParseError::SyntaxError),
+        };
+        if q.contains(|c: char| !c.is_alphabetic() && c != ',' && !c.is_whitespace()) {
+            let generator: String = match q.find(|c: char| c.is_alphabetic()) {
+                Some(i) => q[i..].chars().take_while(|c| c.is_alphabetic()).collect(),
+                None => return Err(ParseError::FieldError),
+            };
+            let unchecked = NumberField { modulus: Vec::new(), generator: generator.clone() };
+            let modulus = match unchecked.parse_poly(q) {
+                Some(m) => m,
+                None => return Err(ParseError::SyntaxError),
+            };
+            return match NumberField::new(modulus, &generator) {
+                Ok(k) => Ok(AnyRing::Algebraic(Arc::new(ring.over(k)))),
+                Err(_) => Err(ParseError::FieldError),
+            };
+        }
 
-    let ord = if ord.trim().chars().collect::<Vec<char>>()[0] == 'l' {
-        MonomialOrdering::Lex
+        let params: Vec<String> = q.split(',').map(|x| x.trim().to_string()).collect();
+        if params.iter().any(|a| ring.symbols.contains(a)) {
+            return Err(ParseError::FieldError);
+        }
+        match parameter_ring(params) {
+            Ok(d) => Ok(AnyRing::Parametric(Arc::new(ring.over(d)))),
+            Err(_) => Err(ParseError::FieldError),
+        }
     } else {
-        MonomialOrdering::DegLex
-    };
-
-    Ok(Ring { symbols: vars, ord })
+        Ok(AnyRing::Rational(Arc::new(ring)))
+    }
 }
-    
 
-fn parse_expression(x: &str, ring: &mut Option<Rc<Ring>>, table: &mut HashMap<String, Item>) -> Result<Item, ParseError> {
+fn eval<F: Field>(x: &str, ring: &Arc<Ring<F>>, table: &mut HashMap<String, Item<F>>) -> Result<String, ParseError> {
     if let Some(("=", s)) = x.split_once(' ') {
         if let Some((name, s)) = s.split_once(' ') {
-            match ring {
-                Some(r) => {
-                    table.insert(name.to_string(), parse_expression_h(s, &r, table)?);
-                    ()
-                },
-                None => return Err(ParseError::RingError),
-            }
+            table.insert(name.to_string(), parse_expression_h(s, ring, table)?);
         }
-        Ok(Item::OK)
-    } else if let Some(("setring", s)) = x.split_once(' ') {
-        let r = parse_ring(s)?;
-        *ring = Some(Rc::new(r));
-        Ok(Item::OK)
+        Ok(String::new())
+    } else {
+        Ok(parse_expression_h(x, ring, table)?.to_string())
+    }
+}
+
+fn parse_expression(x: &str, ring: &mut Option<AnyRing>, tables: &mut Tables) -> Result<String, ParseError> {
+    if let Some(("setring", s)) = x.split_once(' ') {
+        *ring = Some(parse_ring(s)?);
+        Ok(String::new())
     } else {
         match ring {
-            Some(r) => parse_expression_h(x, &r, table),
+            Some(AnyRing::Rational(r)) => eval(x, r, &mut tables.rational),
+            Some(AnyRing::Modular(r)) => eval(x, r, &mut tables.modular),
+            Some(AnyRing::Extension(r)) => eval(x, r, &mut tables.extension),
+            Some(AnyRing::Parametric(r)) => eval(x, r, &mut tables.parametric),
+            Some(AnyRing::Algebraic(r)) => eval(x, r, &mut tables.algebraic),
             None => Err(ParseError::RingError),
         }
     }
 }
 
 fn main() {
-    let mut items = HashMap::new();
-    let mut ring: Option<Rc<Ring>> = None;
+    let mut tables = Tables::default();
+    let mut ring: Option<AnyRing> = None;
 
     loop {
         let mut s = String::new();
@@ -201,15 +327,19 @@ fn main() {
             break;
         }
 
-        let token = match parse_expression(&s, &mut ring, &mut items) {
-            Ok(item) => item.to_string(),
+        let token = match parse_expression(&s, &mut ring, &mut tables) {
+            Ok(item) => item,
             Err(err) => match err {
                 ParseError::InvalidOperation => "ParseError: Invalid operation attempted".to_string(),
                 ParseError::ArgumentError => 
                 "Operation was applied with invalid arguments. Most operations take two polynomials."
                 .to_string(),
                 ParseError::RingError => "RingError: A ring must be provided".to_string(),
+                ParseError::OrderingError => "OrderingError: Invalid monomial ordering for this ring".to_string(),
+                ParseError::FieldError => "FieldError: Invalid coefficient field".to_string(),
                 ParseError::SyntaxError => "ParseError: Invalid syntax".to_string(),
+                ParseError::OperationError(e) => format!("OperationError: {}", e),
+                ParseError::PolynomialError(e) => format!("ParseError: {}", e),
             }
         };
 
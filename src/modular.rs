@@ -0,0 +1,221 @@
+extern crate rug;
+
+use super::error::PolyError;
+use super::fields::*;
+use super::operations::*;
+use super::polynomials::*;
+
+use std::sync::Arc;
+use rug::{Integer, Rational};
+
+// Grobner bases over Q computed modulo several primes.
+//
+// Coefficients in Buchberger's algorithm over Q can grow far beyond those
+//   of the input and the result, while over Z/p they stay word-sized. The
+//   reduced bases modulo a number of primes are combined with the Chinese
+//   remainder theorem, and each coefficient is then recovered as the
+//   smallest fraction matching its residue.
+
+// Lead monomials and supports of a reduced basis. Bases modulo different
+//   primes can only be combined when they agree on these, and a prime
+//   whose basis disagrees with most of the others is unlucky.
+type Shape = Vec<Vec<Exponents>>;
+
+struct Candidate {
+    shape: Shape,
+    residues: Vec<Vec<Integer>>,
+    modulus: Integer,
+    last: Option<PolySet>,
+}
+
+fn shape<F: Coefficient>(g: &PolySet<F>) -> Shape {
+    g.0.iter()
+        .map(|p| p.terms.iter().map(|m| m.degree.clone()).collect())
+        .collect()
+}
+
+// Sorts a basis by decreasing lead monomial, so bases computed along
+//   different paths line up.
+fn sort_basis<F: Coefficient>(g: &mut PolySet<F>) {
+    g.0.sort_by(|a, b| b.terms[0].cmp(&a.terms[0]));
+}
+
+fn mod_p(c: &Rational, p: u64) -> Option<Zp> {
+    let n = c.numer().mod_u(p as u32);
+    let d = c.denom().mod_u(p as u32);
+    Zp::new(i64::from(n), p).checked_div(&Zp::new(i64::from(d), p))
+}
+
+// Maps a polynomial over Q into Z/p, or None when p divides one of the
+//   denominators.
+fn poly_mod_p(f: &Polynomial, ring: &Arc<Ring<Zp>>) -> Option<Polynomial<Zp>> {
+    let mut terms = Vec::new();
+    for m in f.terms.iter() {
+        let c = mod_p(&m.coefficient, ring.domain)?;
+        if !c.is_zero() {
+            terms.push(Monomial { coefficient: c, degree: m.degree.clone(), ring: Arc::clone(ring) });
+        }
+    }
+    Some(Polynomial { length: terms.len(), terms, ring: Arc::clone(ring) })
+}
+
+// Finds a fraction a/b congruent to r mod m with |a| and b both below
+//   sqrt(m/2), using the extended Euclidean algorithm. Such a fraction
+//   is unique when it exists.
+fn rational_reconstruction(r: &Integer, m: &Integer) -> Option<Rational> {
+    let bound = Integer::from(m / 2u32).sqrt();
+
+    let (mut r0, mut r1) = (m.clone(), r.clone());
+    let (mut t0, mut t1) = (Integer::new(), Integer::from(1));
+    while r1 > bound {
+        let (q, r) = r0.div_rem_floor(r1.clone());
+        r0 = r1;
+        r1 = r;
+        let t = Integer::from(&t0 - &q * &t1);
+        t0 = t1;
+        t1 = t;
+    }
+
+    if t1 == 0 || Integer::from(t1.abs_ref()) > bound || Integer::from(r1.gcd_ref(&t1)) != 1 {
+        return None;
+    }
+    Some(Rational::from((r1, t1)))
+}
+
+// Adds the residues of a basis mod p to a candidate by CRT.
+fn combine(c: &mut Candidate, g: &PolySet<Zp>, p: u64) {
+    let p = Integer::from(p);
+    let inv = c.modulus.clone().invert(&p).expect("Primes are distinct");
+
+    for (rs, f) in c.residues.iter_mut().zip(&g.0) {
+        for (r, m) in rs.iter_mut().zip(&f.terms) {
+            let a = Integer::from(m.coefficient.value());
+            let k = Integer::from(&a - &*r) * &inv;
+            let k = k.div_rem_euc(p.clone()).1;
+            *r += k * &c.modulus;
+        }
+    }
+    c.modulus *= p;
+}
+
+fn reconstruct(c: &Candidate, ring: &Arc<Ring>) -> Option<PolySet> {
+    let mut g = Vec::new();
+    for (rs, ds) in c.residues.iter().zip(&c.shape) {
+        let mut terms = Vec::new();
+        for (r, d) in rs.iter().zip(ds) {
+            let coefficient = rational_reconstruction(r, &c.modulus)?;
+            terms.push(Monomial { coefficient, degree: d.clone(), ring: Arc::clone(ring) });
+        }
+        g.push(Polynomial { length: terms.len(), terms, ring: Arc::clone(ring) });
+    }
+    Some(PolySet(g))
+}
+
+// Checks over Q that g is a Grobner basis, by reducing all of its
+//   S-polynomials, and that it contains the ideal of ps.
+//
+//   That the ideal of g is no larger is only checked modulo the first
+//   prime q below p whose image of ps and g is defined, where g must be
+//   the basis of ps mod q. The primes tried by `grobner_basis_modular`
+//   decrease, so q hasn't been used to build g.
+fn verify(g: &PolySet, ps: &PolySet, mut q: u64) -> Result<bool, PolyError> {
+    let mut gs = g.clone();
+    for i in 0..g.0.len() {
+        for j in i + 1..g.0.len() {
+            let s = s_poly(&g.0[i], &g.0[j])?;
+            if !divide_poly_set(&s, &mut gs)?.1.terms.is_empty() {
+                return Ok(false);
+            }
+        }
+    }
+    for f in ps.0.iter() {
+        if !divide_poly_set(f, &mut gs)?.1.terms.is_empty() {
+            return Ok(false);
+        }
+    }
+
+    let ring = &ps.0[0].ring;
+    loop {
+        q -= 1;
+        while !is_prime(q) {
+            q -= 1;
+        }
+
+        let zring = Arc::new((**ring).clone().over::<Zp>(q));
+        let fs: Option<Vec<Polynomial<Zp>>> = ps.0.iter().map(|f| poly_mod_p(f, &zring)).collect();
+        let hs: Option<Vec<Polynomial<Zp>>> = g.0.iter().map(|f| poly_mod_p(f, &zring)).collect();
+        if let (Some(fs), Some(hs)) = (fs, hs) {
+            let b = grobner_basis(&PolySet(fs))?;
+            return Ok(b.0.len() == hs.len() && hs.iter().all(|h| b.0.contains(h)));
+        }
+    }
+}
+
+// Calculates the reduced Grobner basis over Q of the ideal generated by
+//   the given polynomials, by running Buchberger's algorithm modulo
+//   primes below 2^31.
+//
+// Primes dividing a denominator of the input are skipped, and bases
+//   with an unusual shape are set aside. A basis is returned once its
+//   reconstruction is unchanged by another prime and it passes the checks
+//   in `verify`, so the result is that of `grobner_basis` unless every
+//   prime involved was unlucky in the same way. Its elements are sorted
+//   by decreasing lead monomial.
+//
+// @pre The polynomials are normalized.
+pub fn grobner_basis_modular(ps: &PolySet) -> Result<PolySet, PolyError> {
+    same_rings(ps)?;
+
+    if ps.0.is_empty() {
+        return Ok(PolySet(Vec::new()));
+    }
+
+    let ring = Arc::clone(&ps.0[0].ring);
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut p: u64 = 1 << 31;
+
+    loop {
+        p -= 1;
+        while !is_prime(p) {
+            p -= 1;
+        }
+
+        let zring = Arc::new((*ring).clone().over::<Zp>(p));
+        let fs: Option<Vec<Polynomial<Zp>>> = ps.0.iter().map(|f| poly_mod_p(f, &zring)).collect();
+        let fs = match fs {
+            Some(fs) => fs,
+            None => continue,
+        };
+
+        let mut g = grobner_basis(&PolySet(fs))?;
+        sort_basis(&mut g);
+        let s = shape(&g);
+
+        let i = match candidates.iter().position(|c| c.shape == s) {
+            Some(i) => i,
+            None => {
+                let residues = s.iter().map(|f| vec![Integer::new(); f.len()]).collect();
+                candidates.push(Candidate { shape: s, residues, modulus: Integer::from(1), last: None });
+                candidates.len() - 1
+            },
+        };
+        combine(&mut candidates[i], &g, p);
+
+        // Only the shape seen most often is worth reconstructing.
+        let best = (0..candidates.len())
+            .max_by_key(|j| candidates[*j].modulus.significant_bits())
+            .unwrap_or(i);
+        if best != i {
+            continue;
+        }
+
+        let c = &mut candidates[i];
+        let h = reconstruct(c, &ring);
+        if let Some(g) = &h {
+            if c.last.as_ref() == Some(g) && verify(g, ps, p)? {
+                return Ok(g.clone());
+            }
+        }
+        c.last = h;
+    }
+}
@@ -0,0 +1,214 @@
+extern crate rug;
+
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use rug::Rational;
+
+use super::dense;
+use super::factorization::irreducible;
+use super::fields::*;
+use super::galois::signed_terms;
+
+// Algebraic number fields Q(alpha), represented as Q[a] / (m(a)) for the
+//   minimal polynomial m of alpha.
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NumberField {
+    // Coefficients of the monic minimal polynomial, lowest degree first.
+    pub modulus: Vec<Rational>,
+    pub generator: String,
+}
+
+// An element of Q(alpha) as a polynomial in the generator of degree
+//   below that of the minimal polynomial, lowest degree first and with no
+//   trailing zeros.
+#[derive(Clone, Debug)]
+pub struct AlgebraicNumber {
+    coeffs: Vec<Rational>,
+    field: Arc<NumberField>,
+}
+
+impl NumberField {
+    // Builds Q(alpha) from the minimal polynomial of alpha, given lowest
+    //   degree first. The polynomial is made monic.
+    //
+    //   It must be irreducible, which is checked by factoring it modulo a
+    //   prime and lifting the factors, so every nonzero element can be
+    //   inverted.
+    pub fn new(modulus: Vec<Rational>, generator: &str) -> Result<Arc<NumberField>, FieldError> {
+        if generator.is_empty() || !generator.chars().all(|c| c.is_alphabetic()) {
+            return Err(FieldError::InvalidGenerator);
+        }
+
+        let m = dense::trim(modulus);
+        if m.len() < 2 {
+            return Err(FieldError::InvalidModulus);
+        }
+
+        let m = dense::monic(&m, &());
+
+        let dm: Vec<Rational> = m.iter().enumerate().skip(1)
+            .map(|(i, c)| Rational::from(c * i as u32))
+            .collect();
+        if m.len() > 2 && (dense::gcd(&m, &dm, &()).len() != 1 || !irreducible(&m)) {
+            return Err(FieldError::Reducible);
+        }
+
+        Ok(Arc::new(NumberField { modulus: m, generator: generator.to_string() }))
+    }
+
+    pub fn degree(&self) -> usize {
+        self.modulus.len() - 1
+    }
+
+    // Parses a polynomial in the generator with rational coefficients,
+    //   such as `a^2 - 1/2a + 3`.
+    pub fn parse_poly(&self, s: &str) -> Option<Vec<Rational>> {
+        let mut v = Vec::new();
+
+        for (sign, t) in signed_terms(s) {
+            if t.is_empty() {
+                return None;
+            }
+            let (c, e) = match t.find(self.generator.as_str()) {
+                Some(i) => {
+                    let (c, rest) = t.split_at(i);
+                    let c = c.trim().trim_end_matches('*').trim();
+                    let rest = &rest[self.generator.len()..];
+                    let e = match rest.trim().strip_prefix('^') {
+                        Some(e) => e.trim().parse::<usize>().ok()?,
+                        None if rest.trim().is_empty() => 1,
+                        None => return None,
+                    };
+                    (c, e)
+                },
+                None => (t, 0),
+            };
+            let c = if c.is_empty() {
+                Rational::from(1)
+            } else {
+                c.parse::<Rational>().ok()?
+            };
+            let c = if sign { -c } else { c };
+
+            if v.len() <= e {
+                v.resize(e + 1, Rational::new());
+            }
+            v[e] += c;
+        }
+
+        Some(dense::trim(v))
+    }
+}
+
+impl AlgebraicNumber {
+    pub fn context(&self) -> &Arc<NumberField> {
+        &self.field
+    }
+
+    // The class of a in Q[a] / (m(a)), i.e. alpha itself.
+    pub fn generator(field: &Arc<NumberField>) -> AlgebraicNumber {
+        AlgebraicNumber::from_coeffs(vec![Rational::new(), Rational::from(1)], field)
+    }
+
+    pub fn from_coeffs(coeffs: Vec<Rational>, field: &Arc<NumberField>) -> AlgebraicNumber {
+        let r = dense::divmod_monic(&coeffs, &field.modulus, &()).1;
+        AlgebraicNumber { coeffs: r, field: Arc::clone(field) }
+    }
+
+    pub fn coeffs(&self) -> &[Rational] {
+        &self.coeffs
+    }
+}
+
+impl PartialEq for AlgebraicNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs &&
+            (Arc::ptr_eq(&self.field, &other.field) || self.field == other.field)
+    }
+}
+
+impl Eq for AlgebraicNumber {}
+
+impl Hash for AlgebraicNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coeffs.hash(state);
+    }
+}
+
+// Rationals print as they are, and anything else as a parenthesized
+//   polynomial in the generator.
+impl Display for AlgebraicNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coeffs.len() <= 1 {
+            return write!(f, "{}", self.coeffs.first().cloned().unwrap_or_default());
+        }
+
+        let a = &self.field.generator;
+        let mut s = String::new();
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if *c == 0 {
+                continue;
+            }
+            if *c < 0 {
+                s += "-";
+            } else if !s.is_empty() {
+                s += "+";
+            }
+            let c = c.clone().abs();
+            let c = if c == 1 && i > 0 { String::new() } else { c.to_string() };
+            s += &match i {
+                0 => c,
+                1 => format!("{}{}", c, a),
+                _ => format!("{}{}^{}", c, a, i),
+            };
+        }
+        write!(f, "({})", s)
+    }
+}
+
+impl Coefficient for AlgebraicNumber {
+    type Domain = Arc<NumberField>;
+
+    fn zero(d: &Arc<NumberField>) -> Self {
+        AlgebraicNumber { coeffs: Vec::new(), field: Arc::clone(d) }
+    }
+    fn one(d: &Arc<NumberField>) -> Self {
+        AlgebraicNumber { coeffs: vec![Rational::from(1)], field: Arc::clone(d) }
+    }
+    fn parse(s: &str, d: &Arc<NumberField>) -> Option<Self> {
+        Some(AlgebraicNumber::from_coeffs(d.parse_poly(s)?, d))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+    fn add(&self, other: &Self) -> Self {
+        AlgebraicNumber { coeffs: dense::add(&self.coeffs, &other.coeffs), field: Arc::clone(&self.field) }
+    }
+    fn sub(&self, other: &Self) -> Self {
+        AlgebraicNumber { coeffs: dense::sub(&self.coeffs, &other.coeffs), field: Arc::clone(&self.field) }
+    }
+    fn mul(&self, other: &Self) -> Self {
+        let v = dense::mul(&self.coeffs, &other.coeffs, &());
+        AlgebraicNumber::from_coeffs(v, &self.field)
+    }
+    fn neg(&self) -> Self {
+        AlgebraicNumber { coeffs: dense::neg(&self.coeffs), field: Arc::clone(&self.field) }
+    }
+}
+
+impl Field for AlgebraicNumber {
+    // The inverse of c is the t with s m + t c = 1 for the minimal
+    //   polynomial m.
+    fn checked_inv(&self) -> Option<Self> {
+        if self.coeffs.is_empty() {
+            return None;
+        }
+
+        let (g, _, t) = dense::ext_gcd(&self.field.modulus, &self.coeffs, &());
+        debug_assert_eq!(g.len(), 1, "an irreducible modulus is coprime to every nonzero element");
+        Some(AlgebraicNumber::from_coeffs(t, &self.field))
+    }
+}
@@ -1,72 +1,42 @@
 extern crate rug;
 
+use super::error::PolyError;
+use super::fields::{Coefficient, Field};
+use super::geobucket::Geobucket;
 use super::polynomials::*;
 
-use std::collections::BinaryHeap;
-use std::rc::Rc;
-use rug::Rational;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::sync::Arc;
 
 // Implementation details for polynomial operatins
 
-// Simply checks if degree vectors are equal
-fn deg_eq(d1: &[u16], d2: &[u16]) -> bool {
-    for i in 0..d1.len() {
-        if d1[i] != d2[i] {
-            return false;
-        }
+// Checks that two operands come from the same ring. Rings are shared
+//   between their polynomials, so this is usually a pointer comparison.
+pub(crate) fn same_ring<F: Coefficient>(a: &Arc<Ring<F>>, b: &Arc<Ring<F>>) -> Result<(), PolyError> {
+    if Arc::ptr_eq(a, b) || a == b {
+        Ok(())
+    } else {
+        Err(PolyError::RingMismatch)
     }
-    true
-}
-
-
-// Runs through adjacent monomials and adds them
-//   together if their degree vectors are identical.
-//
-//   If the resulting monomial has a coefficient of
-//   0, remove it from the final polynomial.
-fn combine_terms(v: &mut Vec<Monomial>) {
-    let mut v0 = Vec::new();
-
-    let ring = Rc::clone(&v[0].ring);
-
-    let mut n = 0;
-    while n < v.len() - 1 {
-        if deg_eq(&v[n].degree, &v[n + 1].degree) {
-            if Rational::from(&v[n].coefficient + &v[n + 1].coefficient) != 0 {
-                v0.push(Monomial { coefficient: v[n].coefficient.clone() + v[n + 1].coefficient.clone(), degree: v[n].get_degree().to_vec(), ring: Rc::clone(&ring) });
-            }
-            n += 2;
-        } else if v[n].coefficient != 0 {
-            v0.push(v[n].clone());
-
-            n += 1;
-
-        } else {
-            n += 1;
-        }
-
-        if n == v.len() - 1  && v[n].coefficient != 0 {
-            v0.push(v[n].clone());
-        }
-    }
-
-        
-    *v = v0;
 }
 
 // Adds two polynomials together. Uses the merge algorithm from
 //   merge sort in order to maintain the orderings.
 //
-//   @pre The polynomials are ordered correctly according to
-//     their ring's monomial ordering.
-pub fn add_polys(f: &Polynomial, g: &Polynomial) -> Polynomial {
+//   @pre The polynomials are normalized, which debug builds check.
+pub fn add_polys<F: Coefficient>(f: &Polynomial<F>, g: &Polynomial<F>) -> Result<Polynomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
+    f.debug_check();
+    g.debug_check();
+
     let t1 = f.get_terms();
     let t2 = g.get_terms();
 
     if t1.is_empty() {
-        return (*g).clone();
+        return Ok((*g).clone());
     } else if t2.is_empty() {
-        return (*f).clone();
+        return Ok((*f).clone());
     }
 
     let mut v = Vec::new();
@@ -89,93 +59,224 @@ pub fn add_polys(f: &Polynomial, g: &Polynomial) -> Polynomial {
 
     combine_terms(&mut v);
 
-    Polynomial {
+    Ok(Polynomial {
         length: v.len(),
         terms: v,
-        ring: Rc::clone(&f.ring),
-    }
+        ring: Arc::clone(&f.ring),
+    })
 }
 
 // Subtracts two polynomials using addition and scalar multiplication
 //   behind the scenes.
-pub fn sub_polys(f: &Polynomial, g: &Polynomial) -> Polynomial {
-    add_polys(f, &scalar_mult(g, Rational::from(-1)))
+pub fn sub_polys<F: Coefficient>(f: &Polynomial<F>, g: &Polynomial<F>) -> Result<Polynomial<F>, PolyError> {
+    add_polys(f, &scalar_mult(g, F::one(&g.ring.domain).neg()))
 }
 
 // Multiplies each monomial coefficent in the polynomial by a
 //   given scalar.
-pub fn scalar_mult(f: &Polynomial, n: Rational) -> Polynomial {
-    let terms: Vec<Monomial> = f.terms.iter()
-        .map(|m| Monomial { coefficient: m.coefficient.clone() * &n, degree: m.degree.clone(), ring: Rc::clone(&m.ring) })
+pub fn scalar_mult<F: Coefficient>(f: &Polynomial<F>, n: F) -> Polynomial<F> {
+    let terms: Vec<Monomial<F>> = f.terms.iter()
+        .map(|m| Monomial { coefficient: m.coefficient.mul(&n), degree: m.degree.clone(), ring: Arc::clone(&m.ring) })
+        .filter(|m| !m.coefficient.is_zero())
         .collect();
     Polynomial {
         length: terms.len(),
         terms,
-        ring: Rc::clone(&f.ring),
+        ring: Arc::clone(&f.ring),
     }
 }
 
 // Helper function for multiplying monomials together.
-pub fn mult_monoms(f : &Monomial, g: &Monomial) -> Monomial {
-    Monomial {
-        coefficient: f.coefficient.clone() * g.coefficient.clone(),
-        degree: f.degree.iter().zip(&g.degree).map(|(x, y)| x + y).collect(),
-        ring: Rc::clone(&f.ring),
+//
+//   Fails if an exponent of the product is too large for the ring.
+pub fn mult_monoms<F: Coefficient>(f : &Monomial<F>, g: &Monomial<F>) -> Result<Monomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
+
+    Ok(Monomial {
+        coefficient: f.coefficient.mul(&g.coefficient),
+        degree: f.degree.checked_mul(&g.degree)?,
+        ring: Arc::clone(&f.ring),
+    })
+}
+
+// The terms of a polynomial in decreasing order, sorting a copy only
+//   if they are out of order.
+fn sorted_terms<F: Coefficient>(f: &Polynomial<F>) -> Cow<'_, [Monomial<F>]> {
+    if f.terms.windows(2).all(|w| w[0] > w[1]) {
+        Cow::Borrowed(&f.terms[..])
+    } else {
+        let mut terms = f.terms.clone();
+        terms.sort_by(|a, b| b.cmp(a));
+        Cow::Owned(terms)
+    }
+}
+
+// A max-heap of the pending products f_i * g_j in multiplication, with at
+//   most one product per term f_i.
+//
+//   Products with the same monomial share a node. A new product is chained
+//   onto a node it meets while rising through the heap, so for dense inputs,
+//   where many products coincide, the heap stays small and each monomial is
+//   popped about once.
+struct ProductHeap<'a, F: Coefficient> {
+    f: &'a [Monomial<F>],
+    g: &'a [Monomial<F>],
+    // Each node is a monomial and the first i of its chain.
+    nodes: Vec<(Exponents, usize)>,
+    // next[i] is the next i in the same chain, and js[i] the current j.
+    next: Vec<Option<usize>>,
+    js: Vec<usize>,
+}
+
+impl<'a, F: Coefficient> ProductHeap<'a, F> {
+    fn new(f: &'a [Monomial<F>], g: &'a [Monomial<F>]) -> Self {
+        ProductHeap { f, g, nodes: Vec::new(), next: vec![None; f.len()], js: vec![0; f.len()] }
+    }
+
+    fn cmp(&self, a: &Exponents, b: &Exponents) -> Ordering {
+        self.f[0].ring.ord.compare_exponents(a, b)
+    }
+
+    // Adds the product f_i * g_j for the current j of i.
+    fn insert(&mut self, i: usize) -> Result<(), ExponentError> {
+        let d = self.f[i].degree.checked_mul(&self.g[self.js[i]].degree)?;
+
+        let mut k = self.nodes.len();
+        while k > 0 {
+            let p = (k - 1) / 2;
+            match self.cmp(&d, &self.nodes[p].0) {
+                Ordering::Greater => k = p,
+                Ordering::Equal => {
+                    self.next[i] = Some(self.nodes[p].1);
+                    self.nodes[p].1 = i;
+                    return Ok(());
+                },
+                Ordering::Less => break,
+            }
+        }
+
+        self.next[i] = None;
+        self.nodes.push((d, i));
+        let mut k = self.nodes.len() - 1;
+        while k > 0 {
+            let p = (k - 1) / 2;
+            if self.cmp(&self.nodes[k].0, &self.nodes[p].0) != Ordering::Greater {
+                break;
+            }
+            self.nodes.swap(k, p);
+            k = p;
+        }
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<(Exponents, usize)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let top = self.nodes.swap_remove(0);
+
+        let n = self.nodes.len();
+        let mut k = 0;
+        loop {
+            let (l, r) = (2 * k + 1, 2 * k + 2);
+            let mut m = k;
+            if l < n && self.cmp(&self.nodes[l].0, &self.nodes[m].0) == Ordering::Greater {
+                m = l;
+            }
+            if r < n && self.cmp(&self.nodes[r].0, &self.nodes[m].0) == Ordering::Greater {
+                m = r;
+            }
+            if m == k {
+                break;
+            }
+            self.nodes.swap(k, m);
+            k = m;
+        }
+        Some(top)
+    }
+
+    fn peek(&self) -> Option<&Exponents> {
+        self.nodes.first().map(|n| &n.0)
     }
 }
 
-// Multiplies two polynomials together. Very similar to the addition algorithm,
-//   but rather than merging two streams, it creates a new list to merge from
-//   from each term in the first polynomial.
+// Multiplies two polynomials together with Johnson's algorithm. Each term of
+//   the shorter polynomial gives a stream of products with the other one,
+//   and the streams are merged through a heap.
 //
-//   These lists are then merged using a priority queue, which here is a binary
-//   heap.
+//   Stream i + 1 only enters the heap once the first product of stream i
+//   has been taken, since it cannot be larger. All products with the
+//   largest monomial are taken from the heap at once and summed, so each
+//   term of the result is written once.
 //
-//   @pre Polynomials are ordered correctly according to the monomial ordering.
-pub fn mult_polys(f: &Polynomial, g: &Polynomial) -> Polynomial {
-    let t1 = &f.terms;
-    let t2 = &g.terms;
-
-    let mut c = Polynomial {
-        length: 0,
-        terms: Vec::new(),
-        ring: Rc::clone(&f.ring),
+//   @pre The polynomials are normalized, which debug builds check.
+pub fn mult_polys<F: Coefficient>(f: &Polynomial<F>, g: &Polynomial<F>) -> Result<Polynomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
+    f.debug_check();
+    g.debug_check();
+
+    let (t1, t2) = if f.terms.len() <= g.terms.len() {
+        (sorted_terms(f), sorted_terms(g))
+    } else {
+        (sorted_terms(g), sorted_terms(f))
     };
 
-    let mut h = BinaryHeap::new();
-    let mut fs = vec![0; t1.len()];
-
-    for i in 0..t1.len() {
-        h.push((mult_monoms(&t1[i], &t2[0]), i));
+    if t1.is_empty() || t2.is_empty() {
+        return Ok(Polynomial::zero(&f.ring));
     }
 
-    while let Some((d, s)) = h.pop() {
-        let p = Polynomial { length: 1, terms: vec![d], ring: Rc::clone(&f.ring) };
+    let mut v = Vec::new();
+    let mut h = ProductHeap::new(&t1, &t2);
+    h.insert(0)?;
+
+    while let Some((degree, i)) = h.pop() {
+        let mut c = F::zero(&f.ring.domain);
+        let mut done = vec![i];
+        while h.peek() == Some(&degree) {
+            if let Some((_, i)) = h.pop() {
+                done.push(i);
+            }
+        }
+
+        let mut chain = Vec::new();
+        for i in done {
+            let mut k = Some(i);
+            while let Some(i) = k {
+                c = c.add(&t1[i].coefficient.mul(&t2[h.js[i]].coefficient));
+                chain.push(i);
+                k = h.next[i];
+            }
+        }
 
-        c = add_polys(&c, &p);
+        for i in chain {
+            if h.js[i] == 0 && i + 1 < t1.len() {
+                h.insert(i + 1)?;
+            }
+            if h.js[i] + 1 < t2.len() {
+                h.js[i] += 1;
+                h.insert(i)?;
+            }
+        }
 
-        if fs[s] < t2.len() - 1 {
-            fs[s] += 1;
-            h.push((mult_monoms(&t1[s], &t2[fs[s]]), s));
+        if !c.is_zero() {
+            v.push(Monomial { coefficient: c, degree, ring: Arc::clone(&f.ring) });
         }
     }
-                   
-    c
+
+    Ok(Polynomial {
+        length: v.len(),
+        terms: v,
+        ring: Arc::clone(&f.ring),
+    })
 }
 
 // Helper function to check if one monomial can divide another.
-pub fn monom_divides(f: &Monomial, g: &Monomial) -> bool {
-    let d1 = &f.degree;
-    let d2 = &g.degree;
-
-    d1.iter()
-        .zip(d2)
-        .map(|(x, y)| x <= y)
-        .all(|x| x)
+pub fn monom_divides<F: Coefficient>(f: &Monomial<F>, g: &Monomial<F>) -> bool {
+    f.degree.divides(&g.degree)
 }
 
 // Helper function to determine if one polynomial can divide another.
-pub fn poly_divides(f: &Polynomial, g: &Polynomial) -> bool {
+pub fn poly_divides<F: Coefficient>(f: &Polynomial<F>, g: &Polynomial<F>) -> bool {
     if f.terms.is_empty() {
         return false;
     } else if g.terms.is_empty() {
@@ -191,47 +292,29 @@ pub fn poly_divides(f: &Polynomial, g: &Polynomial) -> bool {
 }
 
 // Helper function to divide monomials. 
-pub fn divide_monoms(f: &Monomial, g: &Monomial) -> Monomial {
-    Monomial { 
-        coefficient: (f.coefficient.clone() / g. coefficient.clone()),
-        degree: f.degree.iter().zip(&g.degree).map(|(x, y)| x - y).collect(),
-        ring: Rc::clone(&f.ring),
-    }
+//
+//   Fails if g does not divide f or has a zero coefficient.
+pub fn divide_monoms<F: Field>(f: &Monomial<F>, g: &Monomial<F>) -> Result<Monomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
+
+    Ok(Monomial { 
+        coefficient: f.coefficient.checked_div(&g.coefficient).ok_or(PolyError::DivisionByZero)?,
+        degree: f.degree.checked_div(&g.degree)?,
+        ring: Arc::clone(&f.ring),
+    })
 }
 
-
-// Divides two polynomials, returning the quotient and the remainder. 
-//   Uses a fairly standard method, where the difference between f
-//   and qg + r is calculated, and then this difference is divided by
-//   g if possible.
+// Divides two polynomials, returning the quotient and the remainder,
+//   which has no term divisible by the leading term of g.
 //
-//   @pre All polynomials are ordered according to the monomial ordering.
-pub fn divide_polys(f: &Polynomial, g: &Polynomial) -> (Polynomial, Polynomial) {
-    let mut q = Polynomial { length: 0, terms: Vec::new(), ring: Rc::clone(&f.ring) };
-    let mut r = Polynomial { length: 0, terms: Vec::new(), ring: Rc::clone(&f.ring) };
-
-    let mut rp;
-
-    loop {
-        rp = sub_polys(&sub_polys(&f, &mult_polys(&q, &g)), &r);
-        if rp.terms.is_empty()  {
-            break;
-        }
-
-        if poly_divides(&g, &rp) {
-            for m in rp.terms {
-                if monom_divides(&g.terms[0], &m) {
-                    q = add_polys(&q, 
-                          &Polynomial::from_monom(
-                              divide_monoms(&m,
-                                            &g.terms[0])));
-                }
-            }
-        } else {
-            r = add_polys(&r, &rp);
-        }
+//   @pre The polynomials are normalized, which debug builds check.
+pub fn divide_polys<F: Field>(f: &Polynomial<F>, g: &Polynomial<F>) -> Result<(Polynomial<F>, Polynomial<F>), PolyError> {
+    if g.terms.is_empty() {
+        return Err(PolyError::DivisionByZero);
     }
-    (q, r)
+
+    let (mut qs, r) = divide_poly_set(f, &mut PolySet(vec![g.clone()]))?;
+    Ok((qs.0.remove(0), r))
 }
 
 // Divides a polynomial by a set of polynomials. Uses the algorithm presented in the
@@ -239,94 +322,105 @@ pub fn divide_polys(f: &Polynomial, g: &Polynomial) -> (Polynomial, Polynomial)
 //   method where if the first polynomial doesn't divide the lead term, then the
 //   second is tried and so on.
 //
-//   @pre All polynomials are ordered according to the monoomial ordering.
-pub fn divide_poly_set(f: &Polynomial, g: &mut PolySet) -> (PolySet, Polynomial) {
-    // Don't know if this is actually more efficient?
-    // g.0.sort_unstable();
-
-    let mut qs: PolySet = PolySet(Vec::new());
-    let mut r = Polynomial { length: 0, terms: vec![], ring: Rc::clone(&f.ring) };
+//   The running polynomial is kept in a geobucket, so each step only merges
+//   the new multiple of a divisor with terms of about its own length.
+//
+//   @pre The polynomials are normalized, which debug builds check.
+pub fn divide_poly_set<F: Field>(f: &Polynomial<F>, g: &mut PolySet<F>) -> Result<(PolySet<F>, Polynomial<F>), PolyError> {
+    f.debug_check();
+    g.0.iter().for_each(Polynomial::debug_check);
+    let mut qs = vec![Vec::new(); g.0.len()];
+    let r = geobucket_reduce(f, g, Some(&mut qs))?;
+
+    let qs = qs.into_iter()
+        .map(|terms| Polynomial { length: terms.len(), terms, ring: Arc::clone(&f.ring) })
+        .collect();
 
-    let mut p = f.clone();
+    Ok((PolySet(qs), r))
+}
 
-    for _ in 0..g.0.len() {
-        qs.0.push(Polynomial { length: 0, terms: vec![], ring: Rc::clone(&f.ring) });
+// Remainder of f on division by g, also recording the terms of each
+//   quotient if asked to. Quotient terms are found in decreasing order, so
+//   they are simply pushed onto the end.
+//
+//   Divisors whose terms are out of order are sorted first, so that the
+//   first term of each is its leading term.
+fn geobucket_reduce<F: Field>(f: &Polynomial<F>, g: &PolySet<F>, mut qs: Option<&mut Vec<Vec<Monomial<F>>>>) -> Result<Polynomial<F>, PolyError> {
+    for h in g.0.iter() {
+        same_ring(&f.ring, &h.ring)?;
     }
-
-    while !p.terms.is_empty() { 
-        let mut div = false;
-        let mut i = 0;
-        while i < qs.0.len() && !div {
-            if poly_divides(&g.0[i].lt(), &p.lt()) {
-                let cancel = divide_polys(&p.lt(), &g.0[i].lt()).0;
-                qs.0[i] = add_polys(&qs.0[i], &cancel);
-                p = sub_polys(&p, &mult_polys(&cancel, &g.0[i]));
-                div = true;
-            } else {
-                i += 1;
-            }
-        }
-        if !div {
-            r = add_polys(&r, &p.lt());
-            p = sub_polys(&p, &p.lt());
+    let g: Vec<Cow<[Monomial<F>]>> = g.0.iter().map(sorted_terms).collect();
+
+    let mut p = Geobucket::from_poly(f);
+    let mut r = Vec::new();
+
+    while let Some(lt) = p.pop_lead() {
+        let i = g.iter().position(|h| !h.is_empty() && monom_divides(&h[0], &lt));
+        match i {
+            Some(i) => {
+                let cancel = divide_monoms(&lt, &g[i][0])?;
+                p.sub_mult(&cancel, &g[i][1..])?;
+                if let Some(qs) = qs.as_mut() {
+                    qs[i].push(cancel);
+                }
+            },
+            None => r.push(lt),
         }
     }
 
-    (qs, r)
+    Ok(Polynomial { length: r.len(), terms: r, ring: Arc::clone(&f.ring) })
 }
 
 // Helper function for optimizing finding the Groebner basis.
-pub fn gcd(f: &Monomial, g: &Monomial) -> Monomial {
+pub fn gcd<F: Coefficient>(f: &Monomial<F>, g: &Monomial<F>) -> Result<Monomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
     if f.degree.len() != g.degree.len() {
-        panic!("Degrees are unequal");
+        return Err(PolyError::LengthMismatch);
     }
 
-    let degree: Vec<u16> = f.degree.iter()
-        .zip(&g.degree)
-        .map(|(x, y)| {
-            if *x > *y {
-                *y
-            } else {
-                *x
-            }
-        })
-        .collect();
+    let degree = f.degree.gcd(&g.degree);
 
-    Monomial { coefficient: Rational::from(1), degree, ring: Rc::clone(&f.ring) }
+    Ok(Monomial { coefficient: F::one(&f.ring.domain), degree, ring: Arc::clone(&f.ring) })
 }
 
 // Helper function for optimizing finding the Groebner basis.
-pub fn lcm(f: &Monomial, g: &Monomial) -> Monomial {
-    divide_monoms(&mult_monoms(f, g), &gcd(f, g))
+pub fn lcm<F: Coefficient>(f: &Monomial<F>, g: &Monomial<F>) -> Result<Monomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
+    if f.degree.len() != g.degree.len() {
+        return Err(PolyError::LengthMismatch);
+    }
+
+    let degree = f.degree.lcm(&g.degree);
+
+    Ok(Monomial { coefficient: f.coefficient.mul(&g.coefficient), degree, ring: Arc::clone(&f.ring) })
 }
 
 // Helper function for optimizing finding the Groebner basis.
-pub fn s_poly(f: &Polynomial, g: &Polynomial) -> Polynomial {
-    if f.terms.is_empty() {
-        return scalar_mult(g, Rational::from(-1));
-    } else if g.terms.is_empty() {
-        return f.clone();
-    }
+pub fn s_poly<F: Field>(f: &Polynomial<F>, g: &Polynomial<F>) -> Result<Polynomial<F>, PolyError> {
+    same_ring(&f.ring, &g.ring)?;
 
-    let m1 = &f.terms[0];
-    let m2 = &g.terms[0];
-
-    let deg = m1.degree.iter()
-        .zip(&m2.degree)
-        .map(|(x, y)| {
-            if *x < *y {
-                *y
-            } else {
-                *x
-            }
-        }).collect();
+    let (m1, m2) = match (f.terms.first(), g.terms.first()) {
+        (Some(m1), Some(m2)) => (m1, m2),
+        (None, _) => return Ok(scalar_mult(g, F::one(&g.ring.domain).neg())),
+        (_, None) => return Ok(f.clone()),
+    };
+
+    let deg = m1.degree.lcm(&m2.degree);
 
-    let m = Monomial { coefficient: Rational::from(1), degree: deg, ring: Rc::clone(&f.ring) };
+    let m = Monomial { coefficient: F::one(&f.ring.domain), degree: deg, ring: Arc::clone(&f.ring) };
 
-    let p1 = Polynomial::from_monom(divide_monoms(&m, m1));
-    let p2 = Polynomial::from_monom(divide_monoms(&m, m2));
+    let p1 = Polynomial::from_monom(divide_monoms(&m, m1)?);
+    let p2 = Polynomial::from_monom(divide_monoms(&m, m2)?);
 
-    sub_polys(&mult_polys(&p1, f), &mult_polys(&p2, g))
+    sub_polys(&mult_polys(&p1, f)?, &mult_polys(&p2, g)?)
+}
+
+// Checks that all polynomials of a set come from the same ring.
+pub(crate) fn same_rings<F: Coefficient>(ps: &PolySet<F>) -> Result<(), PolyError> {
+    match ps.0.first() {
+        Some(f) => ps.0.iter().try_for_each(|g| same_ring(&f.ring, &g.ring)),
+        None => Ok(()),
+    }
 }
 
 // Calculates the Grobner basis for the ideal generated by the
@@ -335,23 +429,27 @@ pub fn s_poly(f: &Polynomial, g: &Polynomial) -> Polynomial {
 // Uses Buchberger's algorithm, with some optimizations that
 //   were suggested in the textbook.
 //
-// @pre All polynomials are ordered according to the monomial
-//   ordering.
-pub fn grobner_basis(ps: &PolySet) -> PolySet {
+// @pre The polynomials are normalized, which debug builds check.
+pub fn grobner_basis<F: Field>(ps: &PolySet<F>) -> Result<PolySet<F>, PolyError> {
+    same_rings(ps)?;
+    ps.0.iter().for_each(Polynomial::debug_check);
+
     let mut s = ps.0.clone();
 
     if s.is_empty() {
-        return PolySet(s);
+        return Ok(PolySet(s));
     }
 
     let mut g = PolySet(Vec::new());
 
     while let Some(f) = s.pop() {
-        let (_qs, r) = divide_poly_set(&f, &mut g);
-        if !r.terms.is_empty() {
+        let r = geobucket_reduce(&f, &g, None)?;
+        if let Some(lr) = r.terms.first() {
             for p in g.0.iter() {
-                if lcm(&r.lm(), &p.lm()) != mult_monoms(&r.lm(), &p.lm()) {
-                    s.push(s_poly(&r, &p));
+                let lp = &p.terms[0];
+                // Pairs with coprime leading monomials reduce to zero.
+                if lr.degree.gcd(&lp.degree).total() != 0 {
+                    s.push(s_poly(&r, p)?);
                 }
             }
             g.0.push(r);
@@ -362,18 +460,93 @@ pub fn grobner_basis(ps: &PolySet) -> PolySet {
 
 // Reduces a Grobner basis using the method presented within
 //   the textbook.
-pub fn reduce(mut g: PolySet) -> PolySet {
+pub fn reduce<F: Field>(mut g: PolySet<F>) -> Result<PolySet<F>, PolyError> {
+    same_rings(&g)?;
+
     let mut gp = Vec::new();
 
     for i in 0..g.0.len() {
         let p = g.0.remove(i);
-        let (_, r) = divide_poly_set(&p, &mut g);
+        let r = geobucket_reduce(&p, &g, None)?;
         g.0.insert(i, p);
         if !r.terms.is_empty() {
-            let r = scalar_mult(&r, Rational::from(1) / &r.terms[0].coefficient);
+            let r = scalar_mult(&r, F::one(&r.ring.domain).div(&r.terms[0].coefficient));
             gp.push(r);
         }
     }
-    PolySet(gp)
+    Ok(PolySet(gp))
+}
+
+// Moves a polynomial into another ring whose variables are a permutation
+//   of its own, where variable j of the new ring is variable perm[j] of
+//   the old one. The terms are re-sorted for the new ring's ordering.
+fn permute_vars<F: Coefficient>(f: &Polynomial<F>, perm: &[usize], ring: &Arc<Ring<F>>) -> Polynomial<F> {
+    let mut terms: Vec<Monomial<F>> = f.terms.iter()
+        .map(|m| Monomial {
+            coefficient: m.coefficient.clone(),
+            degree: Exponents::with_width(&perm.iter().map(|i| m.degree.get(*i)).collect::<Vec<u64>>(), ring.width)
+                .expect("Exponents fit the same width"),
+            ring: Arc::clone(ring),
+        })
+        .collect();
+    terms.sort_by(|a, b| b.cmp(a));
+
+    Polynomial {
+        length: terms.len(),
+        terms,
+        ring: Arc::clone(ring),
+    }
 }
 
+// Computes the elimination ideal of the given ideal with respect to the
+//   named variables, i.e. all of its polynomials that do not involve them.
+//
+// The variables to eliminate are moved to the front of a block ordering,
+//   so the Grobner basis elements free of them generate the elimination
+//   ideal. Those are only a Grobner basis for grevlex on the remaining
+//   variables, so they are moved back to the original ring and the result
+//   is their reduced Grobner basis there, for the ring's own ordering.
+pub fn eliminate<F: Field>(ideal: &PolySet<F>, vars: &[&str]) -> Result<PolySet<F>, PolyError> {
+    same_rings(ideal)?;
+
+    if ideal.0.is_empty() {
+        return Ok(PolySet(Vec::new()));
+    }
+
+    let ring = Arc::clone(&ideal.0[0].ring);
+
+    let mut perm = Vec::new();
+    for v in vars {
+        match ring.symbols.iter().position(|s| s == v) {
+            Some(i) => perm.push(i),
+            None => return Err(PolyError::UnknownVariable(v.to_string())),
+        }
+    }
+    perm.sort_unstable();
+    perm.dedup();
+
+    let k = perm.len();
+    let rest: Vec<usize> = (0..ring.symbols.len()).filter(|i| !perm.contains(i)).collect();
+    perm.extend(rest);
+
+    let mut inv = vec![0; perm.len()];
+    for (j, i) in perm.iter().enumerate() {
+        inv[*i] = j;
+    }
+
+    let block = Arc::new(Ring {
+        symbols: perm.iter().map(|i| ring.symbols[*i].clone()).collect(),
+        ord: MonomialOrdering::Block(k, Box::new(MonomialOrdering::DegRevLex), Box::new(MonomialOrdering::DegRevLex)),
+        domain: ring.domain.clone(),
+        width: ring.width,
+    });
+
+    let ps = PolySet(ideal.0.iter().map(|f| permute_vars(f, &perm, &block)).collect());
+
+    let g = grobner_basis(&ps)?.0.into_iter()
+        .filter(|p| p.terms.iter().all(|m| m.degree.iter().take(k).all(|d| d == 0)))
+        .map(|p| permute_vars(&p, &inv, &ring))
+        .collect();
+
+    grobner_basis(&PolySet(g))
+}
@@ -0,0 +1,495 @@
+extern crate rug;
+
+use super::error::PolyError;
+use super::fields::Coefficient;
+use super::operations::mult_polys;
+use super::polynomials::*;
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::Arc;
+
+// Parsing of polynomials written the usual way, like `x^2 - 3/2*x*y + 1`,
+//   and of rings and orderings written as they are displayed, like
+//   `[x, y, z] grevlex`.
+//
+// A polynomial is a sum of terms, and a term is a product of factors
+//   separated by `*` or simply written next to each other, as in `3x^2y`.
+//   A factor is a number, a variable of the ring or an expression in
+//   parentheses, optionally raised to a power with `^`.
+//
+// Coefficients other than integers and fractions are written in
+//   parentheses, like `(1/2a-1)` over a number field, and anything in
+//   parentheses that the coefficient field can't parse is read as a
+//   polynomial instead. Names that aren't variables of the ring are also
+//   handed to the coefficient field, so `i*x` works over the Gaussian
+//   rationals. Variables are matched longest first, so `xy` is x times y
+//   unless the ring has a variable called xy.
+//
+// Exponents of variables are bounded by the exponent width of the ring.
+//   Coefficients take up more room with each power instead, so their
+//   powers, and those of parenthesized expressions with no variables, are
+//   at most MAX_COEFFICIENT_POWER.
+
+pub const MAX_COEFFICIENT_POWER: u64 = u16::MAX as u64;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    UnexpectedEnd,
+    UnknownSymbol(String),
+    InvalidCoefficient(String),
+    InvalidExponent(String),
+    InvalidNumber(String),
+    InvalidVariable(String),
+    ExponentOverflow,
+    UnknownOrdering(String),
+}
+
+// A syntax error, with the byte offset in the input where it was found.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseErrorKind::UnknownSymbol(s) => write!(f, "unknown variable or coefficient `{}`", s),
+            ParseErrorKind::InvalidCoefficient(s) => write!(f, "invalid coefficient `{}`", s),
+            ParseErrorKind::InvalidExponent(s) => write!(f, "invalid exponent `{}`", s),
+            ParseErrorKind::InvalidNumber(s) => write!(f, "invalid number `{}`", s),
+            ParseErrorKind::InvalidVariable(s) => write!(f, "invalid variable name `{}`", s),
+            ParseErrorKind::ExponentOverflow => write!(f, "exponent too large"),
+            ParseErrorKind::UnknownOrdering(s) => write!(f, "unknown monomial ordering `{}`", s),
+        }?;
+        write!(f, " at column {}", self.position + 1)
+    }
+}
+
+impl Error for ParseError {}
+
+fn error<T>(position: usize, kind: ParseErrorKind) -> Result<T, ParseError> {
+    Err(ParseError { position, kind })
+}
+
+// Raises a coefficient to a power by repeated squaring.
+fn pow<F: Coefficient>(x: &F, mut e: u64, d: &F::Domain) -> F {
+    let mut r = F::one(d);
+    let mut b = x.clone();
+    while e > 0 {
+        if e & 1 == 1 {
+            r = r.mul(&b);
+        }
+        e >>= 1;
+        if e > 0 {
+            b = b.mul(&b);
+        }
+    }
+    r
+}
+
+struct Parser<'a, F: Coefficient> {
+    s: &'a str,
+    pos: usize,
+    ring: &'a Arc<Ring<F>>,
+}
+
+impl<'a, F: Coefficient> Parser<'a, F> {
+    // The next character that isn't whitespace, which is skipped.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.s[self.pos..].chars().next()
+    }
+
+    // Takes characters while they match, returning them.
+    fn take(&mut self, p: impl Fn(char) -> bool) -> &'a str {
+        let s: &'a str = self.s;
+        let rest = &s[self.pos..];
+        let n = rest.find(|c: char| !p(c)).unwrap_or(rest.len());
+        self.pos += n;
+        &rest[..n]
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(d) if d == c => {
+                self.pos += c.len_utf8();
+                Ok(())
+            },
+            Some(d) => error(self.pos, ParseErrorKind::UnexpectedCharacter(d)),
+            None => error(self.pos, ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    // Skips any signs before a term, returning whether they negate it.
+    fn signs(&mut self) -> bool {
+        let mut negative = false;
+        loop {
+            match self.peek() {
+                Some('+') => self.pos += 1,
+                Some('-') => {
+                    self.pos += 1;
+                    negative = !negative;
+                },
+                _ => return negative,
+            }
+        }
+    }
+
+    fn sum(&mut self) -> Result<Polynomial<F>, ParseError> {
+        let mut terms = Vec::new();
+        let mut negative = self.signs();
+        loop {
+            for mut m in self.term()? {
+                if negative {
+                    m.coefficient = m.coefficient.neg();
+                }
+                terms.push(m);
+            }
+            match self.peek() {
+                Some('+') | Some('-') => negative = self.signs(),
+                _ => break,
+            }
+        }
+        Ok(Polynomial::from_terms(terms, self.ring))
+    }
+
+    // A product of factors. Numbers and variables are collected into a
+    //   single monomial, which is then multiplied by any parenthesized
+    //   polynomials.
+    fn term(&mut self) -> Result<Vec<Monomial<F>>, ParseError> {
+        let start = self.pos;
+        let mut c = F::one(&self.ring.domain);
+        let mut v = vec![0; self.ring.symbols.len()];
+        let mut rest = None;
+
+        self.factor(&mut c, &mut v, &mut rest)?;
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    self.factor(&mut c, &mut v, &mut rest)?;
+                },
+                Some(d) if d == '(' || d == '_' || d.is_alphanumeric() => self.factor(&mut c, &mut v, &mut rest)?,
+                _ => break,
+            }
+        }
+
+        let degree = match Exponents::with_width(&v, self.ring.width) {
+            Ok(d) => d,
+            Err(_) => return error(start, ParseErrorKind::ExponentOverflow),
+        };
+        let m = Polynomial::from_monom(Monomial { coefficient: c, degree, ring: Arc::clone(self.ring) });
+        match rest {
+            None => Ok(m.terms),
+            Some(p) => match mult_polys(&m, &p) {
+                Ok(p) => Ok(p.terms),
+                Err(_) => error(start, ParseErrorKind::ExponentOverflow),
+            },
+        }
+    }
+
+    fn factor(&mut self, c: &mut F, v: &mut [u64], rest: &mut Option<Polynomial<F>>) -> Result<(), ParseError> {
+        let start = match self.peek() {
+            Some(_) => self.pos,
+            None => return error(self.pos, ParseErrorKind::UnexpectedEnd),
+        };
+        let ring: &'a Arc<Ring<F>> = self.ring;
+        let d = &ring.domain;
+        let full: &'a str = self.s;
+        let s = &full[start..];
+
+        if s.starts_with(|c: char| c.is_ascii_digit()) {
+            let n = self.take(|c| c.is_ascii_digit() || c == '/');
+            let x = match F::parse(n, d) {
+                Some(x) => x,
+                None => return error(start, ParseErrorKind::InvalidCoefficient(n.to_string())),
+            };
+            *c = c.mul(&pow(&x, self.exponent_below(MAX_COEFFICIENT_POWER)?, d));
+        } else if let Some(inner) = s.strip_prefix('(') {
+            let i = match closing_paren(inner) {
+                Some(i) => i,
+                None => return error(self.s.len(), ParseErrorKind::UnexpectedEnd),
+            };
+            if let Some(x) = F::parse(inner[..i].trim(), d) {
+                self.pos += i + 2;
+                *c = c.mul(&pow(&x, self.exponent_below(MAX_COEFFICIENT_POWER)?, d));
+            } else {
+                self.pos += 1;
+                let p = self.sum()?;
+                self.expect(')')?;
+                let mut e = if p.terms.iter().all(|m| m.degree.total() == 0) {
+                    self.exponent_below(MAX_COEFFICIENT_POWER)?
+                } else {
+                    self.exponent()?
+                };
+                let mut q = rest.take().unwrap_or_else(|| Polynomial::from_monom(Monomial {
+                    coefficient: F::one(d),
+                    degree: Exponents::zero_with_width(v.len(), self.ring.width),
+                    ring: Arc::clone(self.ring),
+                }));
+
+                // The power has e times the largest exponent of each variable
+                //   in p, and the term has what was read before it on top, so
+                //   one that doesn't fit fails before multiplying.
+                let max = |f: &Polynomial<F>, k: usize| f.terms.iter().map(|m| m.degree.get(k)).max().unwrap_or(0);
+                for (k, a) in v.iter().enumerate() {
+                    let n = max(&p, k).checked_mul(e).and_then(|n| n.checked_add(max(&q, k)))
+                        .and_then(|n| n.checked_add(*a));
+                    if n.is_none_or(|n| n > self.ring.width.max()) {
+                        return error(start, ParseErrorKind::ExponentOverflow);
+                    }
+                }
+
+                // Repeated squaring, so that large powers of constants are
+                //   quick.
+                let mut b = p;
+                while e > 0 {
+                    if e & 1 == 1 {
+                        q = match mult_polys(&q, &b) {
+                            Ok(q) => q,
+                            Err(_) => return error(start, ParseErrorKind::ExponentOverflow),
+                        };
+                    }
+                    e >>= 1;
+                    if e > 0 {
+                        b = match mult_polys(&b, &b) {
+                            Ok(b) => b,
+                            Err(_) => return error(start, ParseErrorKind::ExponentOverflow),
+                        };
+                    }
+                }
+                *rest = Some(q);
+            }
+        } else if s.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            let var = self.ring.symbols.iter().enumerate()
+                .filter(|(_, x)| !x.is_empty() && s.starts_with(x.as_str()))
+                .max_by_key(|(_, x)| x.len());
+            match var {
+                Some((k, x)) => {
+                    self.pos += x.len();
+                    v[k] = match v[k].checked_add(self.exponent()?) {
+                        Some(e) => e,
+                        None => return error(start, ParseErrorKind::ExponentOverflow),
+                    };
+                },
+                None => {
+                    let name = self.take(|c| c.is_alphanumeric() || c == '_');
+                    let x = match F::parse(name, d) {
+                        Some(x) => x,
+                        None => return error(start, ParseErrorKind::UnknownSymbol(name.to_string())),
+                    };
+                    *c = c.mul(&pow(&x, self.exponent_below(MAX_COEFFICIENT_POWER)?, d));
+                },
+            }
+        } else if let Some(x) = s.chars().next() {
+            return error(start, ParseErrorKind::UnexpectedCharacter(x));
+        }
+        Ok(())
+    }
+
+    // An optional power after a factor, which is 1 when there is none and
+    //   is at most the largest exponent of the ring.
+    fn exponent(&mut self) -> Result<u64, ParseError> {
+        self.exponent_below(self.ring.width.max())
+    }
+
+    // As `exponent`, but bounded by max instead of by the ring.
+    fn exponent_below(&mut self, max: u64) -> Result<u64, ParseError> {
+        if self.peek() != Some('^') {
+            return Ok(1);
+        }
+        self.pos += 1;
+        let start = match self.peek() {
+            Some(_) => self.pos,
+            None => return error(self.pos, ParseErrorKind::UnexpectedEnd),
+        };
+        let e = self.take(|c| c.is_ascii_digit());
+        match e.parse::<u64>() {
+            Ok(e) if e > max => error(start, ParseErrorKind::ExponentOverflow),
+            Ok(e) => Ok(e),
+            Err(_) if e.is_empty() => match self.s[start..].chars().next() {
+                Some(x) => error(start, ParseErrorKind::UnexpectedCharacter(x)),
+                None => error(start, ParseErrorKind::UnexpectedEnd),
+            },
+            Err(_) => error(start, ParseErrorKind::InvalidExponent(e.to_string())),
+        }
+    }
+}
+
+impl<F: Coefficient> Polynomial<F> {
+    // Parses a polynomial in the ring, as described at the top of this
+    //   file. The result is normalized.
+    pub fn parse(s: &str, ring: &Arc<Ring<F>>) -> Result<Self, ParseError> {
+        let mut p = Parser { s, pos: 0, ring };
+        let f = p.sum()?;
+        match p.peek() {
+            Some(c) => error(p.pos, ParseErrorKind::UnexpectedCharacter(c)),
+            None => Ok(f),
+        }
+    }
+}
+
+// Writes a polynomial in a ring, panicking with the parse error if the
+//   literal is invalid.
+//
+//   let f = poly!(ring, "x^2 - 3/2*x*y + 1");
+#[macro_export]
+macro_rules! poly {
+    ($ring:expr, $s:expr) => {{
+        let s: &str = $s;
+        match $crate::polynomials::Polynomial::parse(s, &$ring) {
+            Ok(f) => f,
+            Err(e) => panic!("Invalid polynomial {:?}: {}", s, e),
+        }
+    }};
+}
+
+// Splits a list on the commas outside of parentheses.
+fn split_list(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            },
+            _ => (),
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn numbers<T: FromStr>(s: &str, offset: usize) -> Result<Vec<T>, ParseError> {
+    s.split(',')
+        .map(|a| a.trim().parse::<T>().or_else(|_| error(offset, ParseErrorKind::InvalidNumber(a.trim().to_string()))))
+        .collect()
+}
+
+// Parses an ordering, with offset the position of s in the whole input
+//   for error messages.
+//
+//   The orderings are `lex`, `deglex` and `grevlex` (or `degrevlex`),
+//   weight vectors followed by the ordering that breaks ties, as in
+//   `w(1, 2, 3) lex`, where the tie breaker defaults to lex, weight
+//   matrices with rows separated by semicolons, as in `m(1, 1; 0, -1)`,
+//   and block orderings `block(k, first, rest)`.
+fn parse_ordering(s: &str, offset: usize) -> Result<MonomialOrdering, ParseError> {
+    let offset = offset + s.len() - s.trim_start().len();
+    let s = s.trim();
+
+    // The contents of `name(...)`, and whatever follows the parentheses.
+    let args = |name: &str| -> Result<Option<(&str, &str)>, ParseError> {
+        let r = match s.strip_prefix(name).and_then(|r| r.strip_prefix('(')) {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+        match closing_paren(r) {
+            Some(i) => Ok(Some((&r[..i], &r[i + 1..]))),
+            None => error(offset + s.len(), ParseErrorKind::UnexpectedEnd),
+        }
+    };
+    let end = |rest: &str| -> Result<(), ParseError> {
+        match rest.trim().chars().next() {
+            Some(c) => error(offset + s.len() - rest.trim_start().len(), ParseErrorKind::UnexpectedCharacter(c)),
+            None => Ok(()),
+        }
+    };
+
+    if let Some((w, tie)) = args("w")? {
+        let weights = numbers(w, offset)?;
+        let tie = if tie.trim().is_empty() {
+            MonomialOrdering::Lex
+        } else {
+            parse_ordering(tie, offset + s.len() - tie.len())?
+        };
+        Ok(MonomialOrdering::Weighted(weights, Box::new(tie)))
+    } else if let Some((m, rest)) = args("m")? {
+        end(rest)?;
+        let rows: Result<Vec<Vec<i64>>, ParseError> = m.split(';').map(|r| numbers(r, offset)).collect();
+        Ok(MonomialOrdering::Matrix(rows?))
+    } else if let Some((b, rest)) = args("block")? {
+        end(rest)?;
+        let parts = split_list(b);
+        if parts.len() != 3 {
+            return error(offset, ParseErrorKind::UnknownOrdering(s.to_string()));
+        }
+        let k = numbers(parts[0], offset)?[0];
+        let inner = offset + "block(".len();
+        let first = parse_ordering(parts[1], inner + parts[0].len() + 1)?;
+        let rest = parse_ordering(parts[2], inner + parts[0].len() + parts[1].len() + 2)?;
+        Ok(MonomialOrdering::Block(k, Box::new(first), Box::new(rest)))
+    } else {
+        match s {
+            "lex" => Ok(MonomialOrdering::Lex),
+            "deglex" => Ok(MonomialOrdering::DegLex),
+            "grevlex" | "degrevlex" => Ok(MonomialOrdering::DegRevLex),
+            _ => error(offset, ParseErrorKind::UnknownOrdering(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for MonomialOrdering {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse_ordering(s, 0)
+    }
+}
+
+// Variable names are identifiers, so that they can be told apart from
+//   coefficients and operators.
+pub(crate) fn is_symbol(v: &str) -> bool {
+    v.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && v.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// Rings over the rationals, written as their distinct variables in
+//   brackets followed by the ordering, as in `[x, y, z] w(1, 2, 3) grevlex`.
+//   Use `over` for other coefficient fields.
+impl FromStr for Ring {
+    type Err = PolyError;
+
+    fn from_str(s: &str) -> Result<Self, PolyError> {
+        let offset = s.len() - s.trim_start().len();
+        let r = match s.trim_start().strip_prefix('[') {
+            Some(r) => r,
+            None => return match s.trim_start().chars().next() {
+                Some(c) => Err(PolyError::Syntax(ParseError { position: offset, kind: ParseErrorKind::UnexpectedCharacter(c) })),
+                None => Err(PolyError::Syntax(ParseError { position: offset, kind: ParseErrorKind::UnexpectedEnd })),
+            },
+        };
+        let (vars, ord) = match r.split_once(']') {
+            Some(a) => a,
+            None => return Err(PolyError::Syntax(ParseError { position: s.len(), kind: ParseErrorKind::UnexpectedEnd })),
+        };
+
+        let mut symbols: Vec<String> = Vec::new();
+        if !vars.trim().is_empty() {
+            let mut at = offset + 1;
+            for v in vars.split(',') {
+                let position = at + v.len() - v.trim_start().len();
+                at += v.len() + 1;
+                let v = v.trim();
+                if !is_symbol(v) || symbols.iter().any(|x| x == v) {
+                    return Err(PolyError::Syntax(ParseError { position, kind: ParseErrorKind::InvalidVariable(v.to_string()) }));
+                }
+                symbols.push(v.to_string());
+            }
+        }
+
+        let ord = parse_ordering(ord, offset + vars.len() + 2)?;
+        Ok(Ring::with_ordering(symbols, ord)?)
+    }
+}
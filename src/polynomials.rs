@@ -1,65 +1,386 @@
 extern crate rug;
 
 use std::cmp::Ordering;
-use std::rc::Rc;
-use rug::Rational;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use rug::{Integer, Rational};
+
+use super::error::PolyError;
+use super::fields::{Coefficient, Field};
+use super::operations::{same_ring, scalar_mult};
+pub use super::exponents::{ExponentError, ExponentWidth, Exponents};
 
 // Polynomial representations and supporting functions. 
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Ring {
+pub struct Ring<F: Coefficient = Rational> {
     pub symbols: Vec<String>,
     pub ord: MonomialOrdering,
+    pub domain: F::Domain,
+    pub width: ExponentWidth,
+}
+
+impl Ring {
+    // Builds a ring over the rationals. Use `over` to change the
+    //   coefficient field.
+    pub fn new(symbols: Vec<String>, ord: MonomialOrdering) -> Ring {
+        Ring { symbols, ord, domain: (), width: ExponentWidth::default() }
+    }
+
+    // Builds a ring ordered by the weighted degree of each monomial, with
+    //   ties broken by the given ordering.
+    pub fn weighted(symbols: Vec<String>, weights: Vec<u32>, tie: MonomialOrdering) -> Result<Ring, OrderingError> {
+        Ring::with_ordering(symbols, MonomialOrdering::Weighted(weights, Box::new(tie)))
+    }
+
+    // Builds a ring with a block ordering, where the first k variables
+    //   are compared using `first` and ties are broken on the remaining
+    //   variables using `rest`.
+    pub fn block(symbols: Vec<String>, k: usize, first: MonomialOrdering, rest: MonomialOrdering) -> Result<Ring, OrderingError> {
+        Ring::with_ordering(symbols, MonomialOrdering::Block(k, Box::new(first), Box::new(rest)))
+    }
+
+    // Builds a ring ordered by a weight matrix, where monomials are compared
+    //   by the first row's weighted degree, then the second row's and so on.
+    //
+    //   The matrix must be square with full rank, so that distinct monomials
+    //   never tie, and the first nonzero entry of each column must be positive,
+    //   so that 1 is the smallest monomial.
+    pub fn matrix(symbols: Vec<String>, rows: Vec<Vec<i64>>) -> Result<Ring, OrderingError> {
+        Ring::with_ordering(symbols, MonomialOrdering::Matrix(rows))
+    }
+
+    // Builds a ring with any ordering, checking that weight vectors and
+    //   matrices fit the number of variables, including those inside
+    //   blocks.
+    pub fn with_ordering(symbols: Vec<String>, ord: MonomialOrdering) -> Result<Ring, OrderingError> {
+        check_ordering(&ord, symbols.len())?;
+        Ok(Ring::new(symbols, ord))
+    }
+
+    // Builds a ring ordered by a user-defined term order.
+    pub fn custom<T: TermOrder + 'static>(symbols: Vec<String>, ord: T) -> Ring {
+        Ring::new(symbols, MonomialOrdering::Custom(CustomOrder(Arc::new(ord))))
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl<F: Coefficient> Ring<F> {
+    // Keeps the variables and ordering of this ring, but takes the
+    //   coefficients from another field.
+    pub fn over<G: Coefficient>(self, domain: G::Domain) -> Ring<G> {
+        Ring { symbols: self.symbols, ord: self.ord, domain, width: self.width }
+    }
+
+    // Keeps everything but the size of the exponents, which start out with
+    //   32 bits each, for exponents up to 2^31 - 1.
+    pub fn with_width(self, width: ExponentWidth) -> Ring<F> {
+        Ring { width, ..self }
+    }
+}
+
+// Rings are shown by their variables and ordering, as in
+//   `[x, y, z] grevlex`. The coefficient field is left out.
+impl<F: Coefficient> Display for Ring<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.symbols.join(", "), self.ord)
+    }
+}
+
+// Checks an ordering on n variables, as described for each kind of
+//   ordering above.
+fn check_ordering(ord: &MonomialOrdering, n: usize) -> Result<(), OrderingError> {
+    match ord {
+        MonomialOrdering::Weighted(w, tie) => {
+            if w.len() != n {
+                return Err(OrderingError::WeightLength);
+            }
+            check_ordering(tie, n)
+        },
+        MonomialOrdering::Block(k, first, rest) => {
+            if *k > n {
+                return Err(OrderingError::BlockSize);
+            }
+            check_ordering(first, *k)?;
+            check_ordering(rest, n - k)
+        },
+        MonomialOrdering::Matrix(rows) => {
+            if rows.len() != n || rows.iter().any(|r| r.len() != n) {
+                return Err(OrderingError::MatrixShape);
+            }
+            if rank(rows) != n {
+                return Err(OrderingError::MatrixRank);
+            }
+            for j in 0..n {
+                match rows.iter().map(|r| r[j]).find(|a| *a != 0) {
+                    Some(a) if a > 0 => continue,
+                    _ => return Err(OrderingError::NotAdmissible),
+                }
+            }
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+// Rank of an integer matrix, by Gaussian elimination over the rationals.
+fn rank(rows: &[Vec<i64>]) -> usize {
+    let mut m: Vec<Vec<Rational>> = rows.iter()
+        .map(|r| r.iter().map(|a| Rational::from(*a)).collect())
+        .collect();
+
+    let cols = m.first().map_or(0, |r| r.len());
+    let mut rank = 0;
+
+    for j in 0..cols {
+        let pivot = match (rank..m.len()).find(|i| m[*i][j] != 0) {
+            Some(i) => i,
+            None => continue,
+        };
+        m.swap(rank, pivot);
+        let (top, bottom) = m.split_at_mut(rank + 1);
+        let p = &top[rank];
+        for r in bottom.iter_mut() {
+            if r[j] != 0 {
+                let c = Rational::from(&r[j] / &p[j]);
+                for (a, b) in r[j..].iter_mut().zip(&p[j..]) {
+                    *a -= Rational::from(&c * b);
+                }
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+// A term order on degree vectors. Implementing this outside the crate and
+//   wrapping it in `MonomialOrdering::Custom` lets a ring use any ordering,
+//   as long as it is a total order compatible with multiplication where 1
+//   is the smallest monomial. Orders are shared across threads along with
+//   their ring, so they have to be thread-safe.
+pub trait TermOrder: Debug + Send + Sync {
+    fn compare(&self, d1: &[u64], d2: &[u64]) -> Ordering;
+}
+
+// Shared handle to a user-defined term order. Two handles are only equal
+//   when they point at the same order.
+#[derive(Clone, Debug)]
+pub struct CustomOrder(pub Arc<dyn TermOrder>);
+
+impl PartialEq for CustomOrder {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CustomOrder {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum MonomialOrdering {
     Lex,
     DegLex,
+    DegRevLex,
+    Weighted(Vec<u32>, Box<MonomialOrdering>),
+    Block(usize, Box<MonomialOrdering>, Box<MonomialOrdering>),
+    Matrix(Vec<Vec<i64>>),
+    Custom(CustomOrder),
 }
 
-#[derive(PartialEq)]
-pub struct PolySet(pub Vec<Polynomial>);
+// Orderings are written as in `Ring`'s `FromStr` implementation, except
+//   for custom ones, which can't be written down.
+impl Display for MonomialOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn row<T: Display>(r: &[T]) -> String {
+            r.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(", ")
+        }
 
-impl Clone for PolySet {
+        match self {
+            MonomialOrdering::Lex => write!(f, "lex"),
+            MonomialOrdering::DegLex => write!(f, "deglex"),
+            MonomialOrdering::DegRevLex => write!(f, "grevlex"),
+            MonomialOrdering::Weighted(w, tie) => write!(f, "w({}) {}", row(w), tie),
+            MonomialOrdering::Block(k, first, rest) => write!(f, "block({}, {}, {})", k, first, rest),
+            MonomialOrdering::Matrix(rows) => {
+                let rows: Vec<String> = rows.iter().map(|r| row(r)).collect();
+                write!(f, "m({})", rows.join("; "))
+            },
+            MonomialOrdering::Custom(_) => write!(f, "custom"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OrderingError {
+    WeightLength,
+    BlockSize,
+    MatrixShape,
+    MatrixRank,
+    NotAdmissible,
+}
+
+fn lex(d1: &[u64], d2: &[u64]) -> Ordering {
+    for (a, b) in d1.iter().zip(d2) {
+        if a < b {
+            return Ordering::Less;
+        } else if a > b {
+            return Ordering::Greater;
+        } else {
+            continue;
+        }
+    }
+    Ordering::Equal
+}
+
+impl MonomialOrdering {
+    // Compares packed exponent vectors. Lex and the graded orderings work
+    //   on whole words, while the others unpack the exponents first.
+    pub fn compare_exponents(&self, d1: &Exponents, d2: &Exponents) -> Ordering {
+        match self {
+            MonomialOrdering::Lex => d1.cmp_lex(d2),
+            MonomialOrdering::DegLex => d1.total().cmp(&d2.total()).then_with(|| d1.cmp_lex(d2)),
+            MonomialOrdering::DegRevLex => d1.total().cmp(&d2.total()).then_with(|| d1.cmp_revlex(d2)),
+            MonomialOrdering::Weighted(w, tie) => {
+                let a1: u128 = d1.iter().zip(w).map(|(d, w)| u128::from(d) * u128::from(*w)).sum();
+                let a2: u128 = d2.iter().zip(w).map(|(d, w)| u128::from(d) * u128::from(*w)).sum();
+                a1.cmp(&a2).then_with(|| tie.compare_exponents(d1, d2))
+            },
+            _ => self.compare(&d1.to_vec(), &d2.to_vec()),
+        }
+    }
+}
+
+impl TermOrder for MonomialOrdering {
+    fn compare(&self, d1: &[u64], d2: &[u64]) -> Ordering {
+        match self {
+            MonomialOrdering::Lex => lex(d1, d2),
+            MonomialOrdering::DegLex => {
+                let a1: u128 = d1.iter().map(|d| u128::from(*d)).sum();
+                let a2: u128 = d2.iter().map(|d| u128::from(*d)).sum();
+                if a1 < a2 {
+                    Ordering::Less
+                } else if a1 > a2 {
+                    Ordering::Greater
+                } else {
+                    lex(d1, d2)
+                }
+            },
+            MonomialOrdering::DegRevLex => {
+                let a1: u128 = d1.iter().map(|d| u128::from(*d)).sum();
+                let a2: u128 = d2.iter().map(|d| u128::from(*d)).sum();
+                if a1 < a2 {
+                    Ordering::Less
+                } else if a1 > a2 {
+                    Ordering::Greater
+                } else {
+                    // Ties are broken by the last variable: the monomial
+                    //   with the smaller exponent there is the larger one.
+                    for (a, b) in d1.iter().zip(d2).rev() {
+                        if a > b {
+                            return Ordering::Less;
+                        } else if a < b {
+                            return Ordering::Greater;
+                        } else {
+                            continue;
+                        }
+                    }
+                    Ordering::Equal
+                }
+            },
+            MonomialOrdering::Weighted(w, tie) => {
+                let a1: u128 = d1.iter().zip(w).map(|(d, w)| u128::from(*d) * u128::from(*w)).sum();
+                let a2: u128 = d2.iter().zip(w).map(|(d, w)| u128::from(*d) * u128::from(*w)).sum();
+                if a1 < a2 {
+                    Ordering::Less
+                } else if a1 > a2 {
+                    Ordering::Greater
+                } else {
+                    tie.compare(d1, d2)
+                }
+            },
+            // The first k variables are compared on their own, and the rest
+            //   are only looked at when those are equal.
+            MonomialOrdering::Block(k, first, rest) => {
+                let k = (*k).min(d1.len());
+                match first.compare(&d1[..k], &d2[..k]) {
+                    Ordering::Equal => rest.compare(&d1[k..], &d2[k..]),
+                    x => x,
+                }
+            },
+            MonomialOrdering::Matrix(rows) => {
+                for r in rows {
+                    let a1: i128 = d1.iter().zip(r).map(|(d, w)| i128::from(*d) * i128::from(*w)).sum();
+                    let a2: i128 = d2.iter().zip(r).map(|(d, w)| i128::from(*d) * i128::from(*w)).sum();
+                    if a1 < a2 {
+                        return Ordering::Less;
+                    } else if a1 > a2 {
+                        return Ordering::Greater;
+                    }
+                }
+                Ordering::Equal
+            },
+            MonomialOrdering::Custom(o) => o.0.compare(d1, d2),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct PolySet<F: Coefficient = Rational>(pub Vec<Polynomial<F>>);
+
+impl<F: Coefficient> Clone for PolySet<F> {
     fn clone(&self) -> Self {
         PolySet(self.0.clone())
     }
 }
 
-impl ToString for PolySet {
-    fn to_string(&self) -> String {
-        if self.0.is_empty() {
-            return String::from("{}");
-        }
+// The canonical forms of `Polynomial`, applied to each generator.
+impl<F: Field> PolySet<F> {
+    pub fn monic(&self) -> Self {
+        PolySet(self.0.iter().map(Polynomial::monic).collect())
+    }
+}
 
-        let mut s = String::new();
+impl PolySet<Rational> {
+    pub fn primitive(&self) -> Self {
+        PolySet(self.0.iter().map(|f| f.primitive()).collect())
+    }
+}
 
-        s += "{ ";
+impl PolySet<Integer> {
+    pub fn primitive(&self) -> Self {
+        PolySet(self.0.iter().map(|f| f.primitive()).collect())
+    }
+}
 
-        for i in 0..self.0.len()-1 {
-            s += &self.0[i].to_string();
-            s += ", ";
+impl<F: Coefficient> Display for PolySet<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "{{}}");
         }
 
-        s += &self.0[self.0.len() - 1].to_string();
-        s += " }";
-
-        s
+        write!(f, "{{ ")?;
+        for (i, p) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", p)?;
+        }
+        write!(f, " }}")
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum MonomError {
     InvalidCoefficient,
     NoAlphaSymbol,
+    InvalidExponent,
+    ExponentOverflow,
 }
 
 #[derive(Eq, Debug)]
-pub struct Monomial {
-    pub coefficient: rug::Rational,
-    pub degree: Vec<u16>,
-    pub ring: Rc<Ring>,
+pub struct Monomial<F: Coefficient = Rational> {
+    pub coefficient: F,
+    pub degree: Exponents,
+    pub ring: Arc<Ring<F>>,
 }
 
 fn find(v: &Vec<String>, s: &str) -> Option<usize> {
@@ -71,25 +392,51 @@ fn find(v: &Vec<String>, s: &str) -> Option<usize> {
     None
 }
 
-impl Monomial {
-    pub fn get_degree(&self) -> &Vec<u16> {
+// Finds the parenthesis closing one that was just opened, skipping over
+//   any nested pairs.
+pub(crate) fn closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+impl<F: Coefficient> Monomial<F> {
+    pub fn get_degree(&self) -> &Exponents {
         &self.degree
     }
-    pub fn from_string(s: &str, ring: Rc<Ring>) -> Result<Monomial, MonomError> {
-        let (h, mut t) = s.split_at(
-            match s.find(|c: char| c.is_alphabetic()) {
-                Some(i) => i,
-                None => {
-                    return Err(MonomError::NoAlphaSymbol)
-                },
-            });
+    pub fn from_string(s: &str, ring: Arc<Ring<F>>) -> Result<Monomial<F>, MonomError> {
+        // Coefficients that need symbols of their own, like (a^2+1) over
+        //   GF(p^k), are written in parentheses before the monomial.
+        let (h, mut t) = match s.strip_prefix('(') {
+            Some(r) => match closing_paren(r) {
+                Some(i) => (&r[..i], &r[i + 1..]),
+                None => return Err(MonomError::InvalidCoefficient),
+            },
+            None => s.split_at(
+                match s.find(|c: char| c.is_alphabetic()) {
+                    Some(i) => i,
+                    None => {
+                        return Err(MonomError::NoAlphaSymbol)
+                    },
+                }),
+        };
+        if !t.contains(|c: char| c.is_alphabetic()) {
+            return Err(MonomError::NoAlphaSymbol);
+        }
 
-        let c: Rational = if h.is_empty() {
-            Rational::from(1)
+        let c = if h.is_empty() {
+            F::one(&ring.domain)
         } else {
-            match h.parse() {
-                Ok(r) => r,
-                Err(_) => {
+            match F::parse(h, &ring.domain) {
+                Some(r) => r,
+                None => {
                     return Err(MonomError::InvalidCoefficient)
                 },
             }
@@ -108,177 +455,280 @@ impl Monomial {
             };
             t = match t.find(|c: char| c.is_alphabetic()) {
                 Some(j) => {
-                    let n = t.split_at(j).0.parse::<u16>().unwrap();
-                    v[k] = n;
+                    v[k] = match t.split_at(j).0.parse::<u64>() {
+                        Ok(n) => n,
+                        Err(_) => return Err(MonomError::InvalidExponent),
+                    };
                     t.split_at(j).1
                 }
                 None => {
-                    v[k] = t.parse::<u16>().unwrap();
+                    v[k] = match t.parse::<u64>() {
+                        Ok(n) => n,
+                        Err(_) => return Err(MonomError::InvalidExponent),
+                    };
                     ""
                 }
             };
         }
-        Ok(Monomial { coefficient: c, degree: v, ring })
+        let degree = match Exponents::with_width(&v, ring.width) {
+            Ok(d) => d,
+            Err(_) => return Err(MonomError::ExponentOverflow),
+        };
+        Ok(Monomial { coefficient: c, degree, ring })
     }
 }
 
-impl Ord for Monomial {
+impl<F: Coefficient> Ord for Monomial<F> {
     fn cmp(&self, other: &Self) -> Ordering {
-        let d1 = &self.degree;
-        let d2 = &other.degree;
-
-        match self.ring.ord {
-            MonomialOrdering::Lex => {
-                for (a, b) in d1.iter().zip(d2) {
-                    if a < b {
-                        return Ordering::Less;
-                    } else if a > b {
-                        return Ordering::Greater;
-                    } else {
-                        continue;
-                    }
-                }
-                Ordering::Equal
-            },
-            MonomialOrdering::DegLex => {
-                let a1: u16 = d1.iter().sum();
-                let a2: u16 = d2.iter().sum();
-                if a1 < a2 {
-                    Ordering::Less
-                } else if a1 > a2 {
-                    Ordering::Greater
-                } else {
-                    for (a, b) in d1.iter().zip(d2) {
-                        if a < b {
-                            return Ordering::Less;
-                        } else if a > b {
-                            return Ordering::Greater;
-                        } else {
-                            continue;
-                        }
-                    }
-                    Ordering::Equal
-                }
-            }
-        }
+        self.ring.ord.compare_exponents(&self.degree, &other.degree)
     }
 }
 
-impl PartialOrd for Monomial {
+impl<F: Coefficient> PartialOrd for Monomial<F> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let d1 = &self.degree;
-        let d2 = &other.degree;
-
-        match self.ring.ord {
-            MonomialOrdering::Lex => {
-                for (a, b) in d1.iter().zip(d2) {
-                    if a < b {
-                        return Some(Ordering::Less);
-                    } else if a > b {
-                        return Some(Ordering::Greater);
-                    } else {
-                        continue;
-                    }
-                }
-                return Some(Ordering::Equal)
-            },
-            MonomialOrdering::DegLex => {
-                let a1: u16 = d1.iter().sum();
-                let a2: u16 = d2.iter().sum();
-                if a1 < a2 {
-                    return Some(Ordering::Less)
-                } else if a1 > a2 {
-                    return Some(Ordering::Greater)
-                } else {
-                    for (a, b) in d1.iter().zip(d2) {
-                        if a < b {
-                            return Some(Ordering::Less);
-                        } else if a > b {
-                            return Some(Ordering::Greater);
-                        } else {
-                            continue;
-                        }
-                    }
-                    return Some(Ordering::Equal);
-                }
-            }
-        }
+        Some(self.cmp(other))
     }
 }
 
-impl PartialEq for Monomial {
+// Monomials are equal when they have the same coefficient and exponents
+//   and belong to equal rings.
+impl<F: Coefficient> PartialEq for Monomial<F> {
     fn eq(&self, other: &Self) -> bool {
-        self.coefficient == other.coefficient &&
-            self.degree.iter().zip(&other.degree)
-            .map(|(x, y)| x == y)
-            .all(|x| x)
+        self.coefficient == other.coefficient && self.degree == other.degree
+            && same_ring(&self.ring, &other.ring).is_ok()
+    }
+}
+
+// Equal rings have the same variables, so hashing those is enough to
+//   keep the hash consistent with equality without hashing the field.
+impl<F: Coefficient + Hash> Hash for Monomial<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ring.symbols.hash(state);
+        self.coefficient.hash(state);
+        self.degree.hash(state);
     }
 }
 
-impl Clone for Monomial {
+impl<F: Coefficient> Clone for Monomial<F> {
     fn clone(&self) -> Self {
         Monomial {
             coefficient: self.coefficient.clone(),
             degree: self.degree.clone(),
-            ring: Rc::clone(&self.ring),
+            ring: Arc::clone(&self.ring),
         }
     }
 }
 
-impl ToString for Monomial {
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-
-        s += &self.coefficient.to_string();
-        for i in 0..self.degree.len() {
-            if self.degree[i] != 0 {
-                s += &format!("{}{}{}", self.ring.symbols[i], "^", self.degree[i].to_string());
+impl<F: Coefficient> Display for Monomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.coefficient)?;
+        for (i, d) in self.degree.iter().enumerate() {
+            if d != 0 {
+                write!(f, "{}^{}", self.ring.symbols[i], d)?;
             }
         }
+        Ok(())
+    }
+}
 
-        s
+// Splits a sum of monomials on each `+` that is not inside the
+//   parentheses around a coefficient.
+fn split_terms(s: &str) -> Vec<&str> {
+    let mut terms = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '+' if depth == 0 => {
+                terms.push(&s[start..i]);
+                start = i + 1;
+            },
+            _ => (),
+        }
     }
+    terms.push(&s[start..]);
+
+    terms
 }
 
+// Runs through adjacent monomials and adds them
+//   together if their degree vectors are identical.
+//
+//   If the resulting monomial has a coefficient of
+//   0, remove it from the final polynomial.
+pub(crate) fn combine_terms<F: Coefficient>(v: &mut Vec<Monomial<F>>) {
+    let mut v0: Vec<Monomial<F>> = Vec::with_capacity(v.len());
+
+    for m in v.drain(..) {
+        match v0.last_mut() {
+            Some(l) if l.degree == m.degree => l.coefficient = l.coefficient.add(&m.coefficient),
+            _ => v0.push(m),
+        }
+    }
+    v0.retain(|m| !m.coefficient.is_zero());
+
+    *v = v0;
+}
+
+// Sorts terms by decreasing monomial and combines them.
+fn normalize<F: Coefficient>(v: &mut Vec<Monomial<F>>) {
+    if v.windows(2).any(|w| w[0] <= w[1]) {
+        v.sort_by(|a, b| b.cmp(a));
+    }
+    combine_terms(v);
+}
+
+// A polynomial is normalized when its terms are in strictly decreasing
+//   order for its ring's ordering, none of them is zero, all of them
+//   belong to its ring, and `length` is the number of terms. Every
+//   operation expects normalized operands and returns normalized results,
+//   so polynomials should be built with `new`, `zero`, `from_monom` or
+//   `from_string` rather than from their fields.
 #[derive(Debug, Eq)]
-pub struct Polynomial {
+pub struct Polynomial<F: Coefficient = Rational> {
     pub length: usize,
-    pub terms: Vec<Monomial>,
-    pub ring: Rc<Ring>,
+    pub terms: Vec<Monomial<F>>,
+    pub ring: Arc<Ring<F>>,
 }
 
-impl Polynomial {
-    pub fn get_terms(&self) -> &Vec<Monomial> {
+impl<F: Coefficient> Polynomial<F> {
+    pub fn get_terms(&self) -> &Vec<Monomial<F>> {
         &self.terms
     }
-    pub fn from_string(s: &str, ring: &Rc<Ring>) -> Result<Self, MonomError> {
-        let terms: Result<Vec<Monomial>, MonomError> = s.split('+')
+    // Builds a normalized polynomial from terms in any order. Fails if a
+    //   term comes from another ring or has the wrong number of variables,
+    //   or if its exponents don't fit the ring's exponent width.
+    pub fn new(terms: Vec<Monomial<F>>, ring: &Arc<Ring<F>>) -> Result<Self, PolyError> {
+        let mut v = Vec::with_capacity(terms.len());
+        for mut m in terms {
+            same_ring(&m.ring, ring)?;
+            if m.degree.len() != ring.symbols.len() {
+                return Err(PolyError::LengthMismatch);
+            }
+            if m.degree.width() != ring.width {
+                m.degree = m.degree.to_width(ring.width)?;
+            }
+            m.ring = Arc::clone(ring);
+            v.push(m);
+        }
+        normalize(&mut v);
+        Ok(Polynomial { length: v.len(), terms: v, ring: Arc::clone(ring) })
+    }
+    // As `new`, for terms already known to belong to the ring.
+    pub(crate) fn from_terms(mut terms: Vec<Monomial<F>>, ring: &Arc<Ring<F>>) -> Self {
+        normalize(&mut terms);
+        Polynomial { length: terms.len(), terms, ring: Arc::clone(ring) }
+    }
+    pub fn zero(ring: &Arc<Ring<F>>) -> Self {
+        Polynomial { length: 0, terms: Vec::new(), ring: Arc::clone(ring) }
+    }
+    pub fn from_string(s: &str, ring: &Arc<Ring<F>>) -> Result<Self, MonomError> {
+        let terms: Result<Vec<Monomial<F>>, MonomError> = split_terms(s).into_iter()
             .map(|s| s.trim())
-            .map(|s| Monomial::from_string(s, Rc::clone(ring))).collect();
-        let t = terms?;
-        Ok(Polynomial { length: t.len(), terms: t, ring: Rc::clone(ring) })
+            .map(|s| Monomial::from_string(s, Arc::clone(ring))).collect();
+        let mut t = terms?;
+        normalize(&mut t);
+        Ok(Polynomial { length: t.len(), terms: t, ring: Arc::clone(ring) })
     }
-    pub fn from_monom(m: Monomial) -> Self {
-        let ring = Rc::clone(&m.ring);
+    // The polynomial with the single term m, which is zero if the
+    //   coefficient of m is.
+    pub fn from_monom(m: Monomial<F>) -> Self {
+        let ring = Arc::clone(&m.ring);
+        let terms = if m.coefficient.is_zero() { Vec::new() } else { vec![m] };
         Polynomial {
-            length: 1,
-            terms: vec![m],
+            length: terms.len(),
+            terms,
             ring,
         }
     }
-    pub fn lt(&self) -> Self {
-        Polynomial::from_monom(self.terms[0].clone())
+    // Checks that the polynomial is normalized, as described above.
+    pub fn is_normalized(&self) -> bool {
+        self.length == self.terms.len()
+            && self.terms.windows(2).all(|w| w[0] > w[1])
+            && self.terms.iter().all(|m| {
+                !m.coefficient.is_zero()
+                    && m.degree.len() == self.ring.symbols.len()
+                    && (Arc::ptr_eq(&m.ring, &self.ring) || m.ring == self.ring)
+            })
     }
-    pub fn lm(&self) -> Monomial {
-        Monomial {
-            coefficient: Rational::from(1),
-            degree: self.terms[0].degree.clone(),
-            ring: Rc::clone(&self.ring),
+    // Asserts the invariants in debug builds. Operations call this on
+    //   their operands, so a polynomial built by hand from its fields is
+    //   caught before it gives wrong results.
+    pub(crate) fn debug_check(&self) {
+        debug_assert!(self.is_normalized(), "Polynomial is not normalized: {}", self);
+    }
+    // The leading term, or None for the zero polynomial.
+    pub fn lt(&self) -> Option<Self> {
+        Some(Polynomial::from_monom(self.terms.first()?.clone()))
+    }
+    // The leading monomial with coefficient 1, or None for the zero
+    //   polynomial.
+    pub fn lm(&self) -> Option<Monomial<F>> {
+        Some(Monomial {
+            coefficient: F::one(&self.ring.domain),
+            degree: self.terms.first()?.degree.clone(),
+            ring: Arc::clone(&self.ring),
+        })
+    }
+}
+
+// Canonical forms, so that generators which differ by a nonzero scalar
+//   become equal. The zero polynomial is left as it is.
+impl<F: Field> Polynomial<F> {
+    // Scales the polynomial so its leading coefficient is 1.
+    pub fn monic(&self) -> Self {
+        match self.terms.first() {
+            Some(m) => scalar_mult(self, F::one(&self.ring.domain).div(&m.coefficient)),
+            None => self.clone(),
         }
     }
 }
 
-impl Ord for Polynomial {
+impl Polynomial<Rational> {
+    // Scales the polynomial to integer coefficients with no common factor
+    //   and a positive leading coefficient, by the lcm of the denominators
+    //   over the gcd of the numerators.
+    pub fn primitive(&self) -> Self {
+        let mut l = Integer::from(1);
+        let mut g = Integer::new();
+        for m in &self.terms {
+            l.lcm_mut(m.coefficient.denom());
+            g.gcd_mut(m.coefficient.numer());
+        }
+        match self.terms.first() {
+            Some(m) if m.coefficient < 0 => scalar_mult(self, -Rational::from((l, g))),
+            Some(_) => scalar_mult(self, Rational::from((l, g))),
+            None => self.clone(),
+        }
+    }
+}
+
+impl Polynomial<Integer> {
+    // Divides out the content, the gcd of the coefficients, and makes the
+    //   leading coefficient positive.
+    pub fn primitive(&self) -> Self {
+        let mut g = Integer::new();
+        for m in &self.terms {
+            g.gcd_mut(&m.coefficient);
+        }
+        if self.terms.first().is_some_and(|m| m.coefficient < 0) {
+            g = -g;
+        }
+        let terms = self.terms.iter()
+            .map(|m| Monomial {
+                coefficient: Integer::from(m.coefficient.div_exact_ref(&g)),
+                degree: m.degree.clone(),
+                ring: Arc::clone(&m.ring),
+            })
+            .collect();
+        Polynomial::from_terms(terms, &self.ring)
+    }
+}
+
+impl<F: Coefficient> Ord for Polynomial<F> {
     fn cmp(&self, other: &Self) -> Ordering {
         for i in 0..self.terms.len() {
             if i >= other.terms.len() {
@@ -294,7 +744,7 @@ impl Ord for Polynomial {
     }
 }
 
-impl PartialOrd for Polynomial {
+impl<F: Coefficient> PartialOrd for Polynomial<F> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         for i in 0..self.terms.len() {
             if i >= other.terms.len() {
@@ -310,9 +760,11 @@ impl PartialOrd for Polynomial {
     }
 }
 
-impl PartialEq for Polynomial {
+impl<F: Coefficient> PartialEq for Polynomial<F> {
     fn eq(&self, other: &Self) -> bool {
-        if self.terms.len() == other.terms.len() {
+        if same_ring(&self.ring, &other.ring).is_err() {
+            false
+        } else if self.terms.len() == other.terms.len() {
             self.terms.iter()
                 .zip(&other.terms)
                 .map(|(x, y)| x == y)
@@ -323,32 +775,41 @@ impl PartialEq for Polynomial {
     }
 }
 
-impl Clone for Polynomial {
+// Hashed like `Monomial`, but with the variables hashed only once.
+impl<F: Coefficient + Hash> Hash for Polynomial<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ring.symbols.hash(state);
+        self.terms.len().hash(state);
+        for m in &self.terms {
+            m.coefficient.hash(state);
+            m.degree.hash(state);
+        }
+    }
+}
+
+impl<F: Coefficient> Clone for Polynomial<F> {
     fn clone(&self) -> Self {
         Polynomial {
             length: self.length,
             terms: self.terms.clone(),
-            ring: Rc::clone(&self.ring),
+            ring: Arc::clone(&self.ring),
         }
     }
 }
 
-impl ToString for Polynomial {
-    fn to_string(&self) -> String {
+impl<F: Coefficient> Display for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.terms.is_empty() {
-            return String::from("0");
+            return write!(f, "0");
         }
 
-        let mut s = String::new();
-
-        for i in 0..self.terms.len() - 1 {
-            s += &self.terms[i].to_string();
-            s += " + ";
+        for (i, m) in self.terms.iter().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}", m)?;
         }
-
-        s += &self.terms[self.terms.len() - 1].to_string();
-
-        s
+        Ok(())
     }
 }
 
@@ -0,0 +1,344 @@
+extern crate rug;
+
+use std::fmt::{self, Display};
+use std::sync::Arc;
+use rug::Rational;
+
+use super::error::PolyError;
+use super::fields::*;
+use super::galois::signed_terms;
+use super::operations::*;
+use super::polynomials::*;
+
+// Rational function fields Q(a, b, ...), for systems whose coefficients
+//   depend on symbolic parameters. The parameters live in a ring of their
+//   own, which is the domain of the field.
+
+// A quotient of two polynomials in the parameters. The numerator and
+//   denominator are kept coprime, with the denominator monic, so equal
+//   functions always have equal representations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RationalFunction {
+    num: Polynomial,
+    den: Polynomial,
+}
+
+// Builds the ring of parameters for Q(a, b, ...). Parameter names must be
+//   distinct and alphabetic, so coefficients such as `2a*b` can be read.
+//
+//   Its exponents are 64 bits wide, while parsed parameters have degree at
+//   most MAX_PARAMETER_DEGREE, which leaves room for about 2^31 products.
+pub fn parameter_ring(symbols: Vec<String>) -> Result<Arc<Ring>, FieldError> {
+    if symbols.is_empty() {
+        return Err(FieldError::InvalidParameters);
+    }
+    for (i, s) in symbols.iter().enumerate() {
+        if s.is_empty() || !s.chars().all(|c| c.is_alphabetic()) || symbols[..i].contains(s) {
+            return Err(FieldError::InvalidParameters);
+        }
+    }
+
+    Ok(Arc::new(Ring::new(symbols, MonomialOrdering::Lex).with_width(ExponentWidth::U64)))
+}
+
+pub const MAX_PARAMETER_DEGREE: u64 = u32::MAX as u64;
+
+const OVERFLOW: &str = "Parameter degree above 2^63";
+
+fn constant(c: Rational, ring: &Arc<Ring>) -> Polynomial {
+    let m = Monomial { coefficient: c, degree: Exponents::zero_with_width(ring.symbols.len(), ring.width), ring: Arc::clone(ring) };
+    Polynomial::from_monom(m)
+}
+
+fn is_constant(f: &Polynomial) -> bool {
+    f.terms.len() == 1 && f.terms[0].degree.total() == 0
+}
+
+fn monic(f: &Polynomial) -> Polynomial {
+    match f.terms.first() {
+        Some(m) => scalar_mult(f, Rational::from(1) / m.coefficient.clone()),
+        None => f.clone(),
+    }
+}
+
+// Monic greatest common divisor of two polynomials in the parameters.
+//
+//   With a single parameter this is Euclid's algorithm. Otherwise the gcd
+//   is fg / lcm(f, g), where the lcm generates the intersection of <f> and
+//   <g>, found by eliminating t from <tf, (1 - t)g>.
+fn poly_gcd(f: &Polynomial, g: &Polynomial) -> Polynomial {
+    let ring = &f.ring;
+    if f.terms.is_empty() {
+        return monic(g);
+    } else if g.terms.is_empty() {
+        return monic(f);
+    } else if is_constant(f) || is_constant(g) {
+        return constant(Rational::from(1), ring);
+    }
+
+    if ring.symbols.len() == 1 {
+        let (mut a, mut b) = (f.clone(), g.clone());
+        while !b.terms.is_empty() {
+            let r = divide_polys(&a, &b).expect(OVERFLOW).1;
+            a = b;
+            b = r;
+        }
+        return monic(&a);
+    }
+
+    // Lex with t first agrees with the parameter ring's lex on polynomials
+    //   free of t, so terms keep their order when t is added or dropped.
+    let mut symbols = vec![String::from("t")];
+    symbols.extend(ring.symbols.iter().cloned());
+    let tring = Arc::new(Ring::new(symbols, MonomialOrdering::Lex).with_width(ExponentWidth::U64));
+
+    let lift = |p: &Polynomial, t: u64| {
+        let terms: Vec<Monomial> = p.terms.iter()
+            .map(|m| {
+                let mut degree = vec![t];
+                degree.extend(m.degree.iter());
+                let degree = Exponents::with_width(&degree, tring.width).expect("Exponents of t are at most 1");
+                Monomial { coefficient: m.coefficient.clone(), degree, ring: Arc::clone(&tring) }
+            })
+            .collect();
+        Polynomial { length: terms.len(), terms, ring: Arc::clone(&tring) }
+    };
+
+    let tf = lift(f, 1);
+    let tg = sub_polys(&lift(g, 0), &lift(g, 1)).expect(OVERFLOW);
+
+    let l = grobner_basis(&PolySet(vec![tf, tg])).expect(OVERFLOW).0.into_iter()
+        .find(|p| p.terms.iter().all(|m| m.degree.get(0) == 0))
+        .expect("The intersection of two principal ideals is principal");
+
+    let terms: Vec<Monomial> = l.terms.iter()
+        .map(|m| {
+            let degree = Exponents::with_width(&m.degree.to_vec()[1..], ring.width).expect("Dropping t keeps the exponents");
+            Monomial { coefficient: m.coefficient.clone(), degree, ring: Arc::clone(ring) }
+        })
+        .collect();
+    let l = Polynomial { length: terms.len(), terms, ring: Arc::clone(ring) };
+
+    monic(&divide_polys(&mult_polys(f, g).expect(OVERFLOW), &l).expect(OVERFLOW).0)
+}
+
+// Parses a polynomial in the parameters, such as `a^2*b - 1/2a + 3`, in
+//   which each parameter has degree at most MAX_PARAMETER_DEGREE.
+fn parse_poly(s: &str, ring: &Arc<Ring>) -> Option<Polynomial> {
+    let mut f = Polynomial::zero(ring);
+
+    for (sign, t) in signed_terms(s) {
+        let (c, mut rest) = t.split_at(t.find(|c: char| c.is_alphabetic()).unwrap_or(t.len()));
+        let c = c.trim().trim_end_matches('*').trim();
+        if c.is_empty() && rest.is_empty() {
+            return None;
+        }
+        let c = if c.is_empty() {
+            Rational::from(1)
+        } else {
+            c.parse::<Rational>().ok()?
+        };
+
+        let mut degree: Vec<u64> = vec![0; ring.symbols.len()];
+        loop {
+            rest = rest.trim_start_matches(|c: char| c == '*' || c.is_whitespace());
+            if rest.is_empty() {
+                break;
+            }
+            // Take the longest parameter name, so `ab` is preferred to `a`
+            //   when both are parameters.
+            let k = (0..ring.symbols.len())
+                .filter(|k| rest.starts_with(ring.symbols[*k].as_str()))
+                .max_by_key(|k| ring.symbols[*k].len())?;
+            rest = rest[ring.symbols[k].len()..].trim_start();
+
+            let e = match rest.strip_prefix('^') {
+                Some(r) => {
+                    let r = r.trim_start();
+                    let n = r.find(|c: char| !c.is_ascii_digit()).unwrap_or(r.len());
+                    rest = &r[n..];
+                    r[..n].parse::<u64>().ok()?
+                },
+                None => 1,
+            };
+            degree[k] = degree[k].checked_add(e).filter(|d| *d <= MAX_PARAMETER_DEGREE)?;
+        }
+
+        if c != 0 {
+            let c = if sign { -c } else { c };
+            let degree = Exponents::with_width(&degree, ring.width).ok()?;
+            let m = Monomial { coefficient: c, degree, ring: Arc::clone(ring) };
+            f = add_polys(&f, &Polynomial::from_monom(m)).ok()?;
+        }
+    }
+
+    Some(f)
+}
+
+// Writes a polynomial in the parameters in the form `parse_poly` reads.
+fn write_poly(f: &Polynomial) -> String {
+    let mut s = String::new();
+
+    for (i, m) in f.terms.iter().enumerate() {
+        let c = m.coefficient.clone().abs();
+        if m.coefficient < 0 {
+            s += "-";
+        } else if i > 0 {
+            s += "+";
+        }
+
+        let vars: Vec<String> = m.degree.iter().enumerate()
+            .filter(|(_, d)| *d > 0)
+            .map(|(k, d)| match d {
+                1 => m.ring.symbols[k].clone(),
+                _ => format!("{}^{}", m.ring.symbols[k], d),
+            })
+            .collect();
+
+        if vars.is_empty() {
+            s += &c.to_string();
+        } else if c == 1 {
+            s += &vars.join("*");
+        } else {
+            s += &format!("{}{}", c, vars.join("*"));
+        }
+    }
+
+    s
+}
+
+// Splits `(p)/(q)` into p and q. The denominator doesn't need
+//   parentheses if it is a single term.
+fn split_quotient(s: &str) -> Option<(&str, &str)> {
+    let r = s.strip_prefix('(')?;
+    let i = closing_paren(r)?;
+
+    let d = r[i + 1..].trim().strip_prefix('/')?.trim();
+    let d = d.strip_prefix('(').and_then(|d| d.strip_suffix(')')).unwrap_or(d);
+    Some((&r[..i], d))
+}
+
+impl RationalFunction {
+    // Builds the quotient of two polynomials in the parameters, cancelling
+    //   their common factors.
+    pub fn new(num: Polynomial, den: Polynomial) -> Result<RationalFunction, PolyError> {
+        if den.terms.is_empty() {
+            return Err(PolyError::DivisionByZero);
+        }
+        Ok(RationalFunction::reduced(num, den))
+    }
+
+    // As `new`, for a denominator known to be nonzero.
+    fn reduced(num: Polynomial, den: Polynomial) -> RationalFunction {
+        let g = poly_gcd(&num, &den);
+        let (num, den) = if is_constant(&g) {
+            (num, den)
+        } else {
+            (divide_polys(&num, &g).expect(OVERFLOW).0, divide_polys(&den, &g).expect(OVERFLOW).0)
+        };
+
+        let c = Rational::from(1) / den.terms[0].coefficient.clone();
+        RationalFunction { num: scalar_mult(&num, c.clone()), den: scalar_mult(&den, c) }
+    }
+
+    pub fn from_poly(f: Polynomial) -> RationalFunction {
+        let den = constant(Rational::from(1), &f.ring);
+        RationalFunction { num: f, den }
+    }
+
+    // The parameter with the given name.
+    pub fn param(name: &str, ring: &Arc<Ring>) -> Option<RationalFunction> {
+        let k = ring.symbols.iter().position(|s| s == name)?;
+        let mut degree = vec![0; ring.symbols.len()];
+        degree[k] = 1;
+
+        let degree = Exponents::with_width(&degree, ring.width).ok()?;
+        let m = Monomial { coefficient: Rational::from(1), degree, ring: Arc::clone(ring) };
+        Some(RationalFunction::from_poly(Polynomial::from_monom(m)))
+    }
+
+    pub fn numer(&self) -> &Polynomial {
+        &self.num
+    }
+
+    pub fn denom(&self) -> &Polynomial {
+        &self.den
+    }
+}
+
+// Constants print as plain rationals, polynomials in parentheses and
+//   anything else as `((p)/(q))`.
+impl Display for RationalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.num.terms.is_empty() {
+            write!(f, "0")
+        } else if !is_constant(&self.den) {
+            write!(f, "(({})/({}))", write_poly(&self.num), write_poly(&self.den))
+        } else if is_constant(&self.num) {
+            write!(f, "{}", self.num.terms[0].coefficient)
+        } else {
+            write!(f, "({})", write_poly(&self.num))
+        }
+    }
+}
+
+impl Coefficient for RationalFunction {
+    type Domain = Arc<Ring>;
+
+    fn zero(d: &Arc<Ring>) -> Self {
+        let num = Polynomial::zero(d);
+        RationalFunction::from_poly(num)
+    }
+    fn one(d: &Arc<Ring>) -> Self {
+        RationalFunction::from_poly(constant(Rational::from(1), d))
+    }
+    // Accepts polynomials in the parameters and quotients of them written
+    //   as `(p)/(q)`, either of which may be wrapped in parentheses.
+    fn parse(s: &str, d: &Arc<Ring>) -> Option<Self> {
+        let mut s = s.trim();
+        while let Some(r) = s.strip_prefix('(') {
+            if closing_paren(r) != Some(r.len() - 1) {
+                break;
+            }
+            s = r[..r.len() - 1].trim();
+        }
+
+        if let Some((n, q)) = split_quotient(s) {
+            return RationalFunction::new(parse_poly(n, d)?, parse_poly(q, d)?).ok();
+        }
+
+        Some(RationalFunction::from_poly(parse_poly(s, d)?))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.terms.is_empty()
+    }
+    fn add(&self, other: &Self) -> Self {
+        if self.den == other.den {
+            return RationalFunction::reduced(add_polys(&self.num, &other.num).expect(OVERFLOW), self.den.clone());
+        }
+        let a = mult_polys(&self.num, &other.den).expect(OVERFLOW);
+        let b = mult_polys(&other.num, &self.den).expect(OVERFLOW);
+        RationalFunction::reduced(add_polys(&a, &b).expect(OVERFLOW), mult_polys(&self.den, &other.den).expect(OVERFLOW))
+    }
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+    fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return RationalFunction::zero(&self.num.ring);
+        }
+        let num = mult_polys(&self.num, &other.num).expect(OVERFLOW);
+        RationalFunction::reduced(num, mult_polys(&self.den, &other.den).expect(OVERFLOW))
+    }
+    fn neg(&self) -> Self {
+        RationalFunction { num: scalar_mult(&self.num, Rational::from(-1)), den: self.den.clone() }
+    }
+}
+
+impl Field for RationalFunction {
+    fn checked_inv(&self) -> Option<Self> {
+        let c = Rational::from(1) / self.num.terms.first()?.coefficient.clone();
+        Some(RationalFunction { num: scalar_mult(&self.den, c.clone()), den: scalar_mult(&self.num, c) })
+    }
+}
@@ -0,0 +1,193 @@
+extern crate serde;
+
+use std::sync::Arc;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+
+use super::error::PolyError;
+use super::fields::Coefficient;
+use super::operations::same_rings;
+use super::parser::is_symbol;
+use super::polynomials::*;
+
+// Serde support, enabled by the `serde` feature. Values are written in a
+//   schema that doesn't depend on the internal representation:
+//
+//   Ring        { "symbols": ["x", "y"], "ordering": "grevlex", "width": 32 }
+//   Monomial    { "ring": ring, "coefficient": "-3/2", "exponents": [1, 1] }
+//   Polynomial  { "ring": ring, "terms": [term, ...] }
+//   PolySet     { "ring": ring or null, "polynomials": [[term, ...], ...] }
+//
+//   where each term is { "coefficient": "-3/2", "exponents": [1, 1] }.
+//   Orderings are written as in `Ring`'s `Display`, and coefficients as
+//   strings so that rationals stay exact whatever the format does with
+//   numbers. A polynomial set shares one ring between its polynomials.
+//
+//   Only fields without extra data, like the rationals, are supported,
+//   since the schema has nowhere to keep a modulus or a minimal
+//   polynomial. Custom orderings can't be serialized either.
+//
+//   Deserializing checks the ring as `Ring::with_ordering` does, builds
+//   it once for everything that shares it, and normalizes each
+//   polynomial, so terms may be given in any order.
+
+#[derive(Serialize, Deserialize)]
+struct RingRepr {
+    symbols: Vec<String>,
+    ordering: String,
+    width: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TermRepr {
+    coefficient: String,
+    exponents: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MonomialRepr {
+    ring: RingRepr,
+    coefficient: String,
+    exponents: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PolynomialRepr {
+    ring: RingRepr,
+    terms: Vec<TermRepr>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PolySetRepr {
+    ring: Option<RingRepr>,
+    polynomials: Vec<Vec<TermRepr>>,
+}
+
+fn ring_repr<F: Coefficient>(ring: &Ring<F>) -> Result<RingRepr, String> {
+    let ordering = ring.ord.to_string();
+    if ordering.parse::<MonomialOrdering>().is_err() {
+        return Err(format!("The ordering {} can't be serialized", ordering));
+    }
+    Ok(RingRepr { symbols: ring.symbols.clone(), ordering, width: ring.width.bits() })
+}
+
+fn term_repr<F: Coefficient>(m: &Monomial<F>) -> TermRepr {
+    TermRepr { coefficient: m.coefficient.to_string(), exponents: m.degree.to_vec() }
+}
+
+fn ring<F: Coefficient<Domain = ()>>(r: RingRepr) -> Result<Ring<F>, String> {
+    if let Some(v) = r.symbols.iter().find(|v| !is_symbol(v)) {
+        return Err(format!("Invalid variable name `{}`", v));
+    }
+    let ord: MonomialOrdering = r.ordering.parse().map_err(|e| format!("Invalid ordering: {}", e))?;
+    let width = match r.width {
+        8 => ExponentWidth::U8,
+        16 => ExponentWidth::U16,
+        32 => ExponentWidth::U32,
+        64 => ExponentWidth::U64,
+        n => return Err(format!("Invalid exponent width {}", n)),
+    };
+    let ring = Ring::with_ordering(r.symbols, ord).map_err(|e| PolyError::from(e).to_string())?;
+    Ok(ring.over(()).with_width(width))
+}
+
+fn term<F: Coefficient>(coefficient: &str, exponents: &[u64], ring: &Arc<Ring<F>>) -> Result<Monomial<F>, String> {
+    if exponents.len() != ring.symbols.len() {
+        return Err(PolyError::LengthMismatch.to_string());
+    }
+    // Coefficients that are shown in parentheses, like Gaussian
+    //   rationals, are parsed without them.
+    let c = coefficient.trim();
+    let c = c.strip_prefix('(').and_then(|c| c.strip_suffix(')')).unwrap_or(c);
+    let coefficient = match F::parse(c, &ring.domain) {
+        Some(c) => c,
+        None => return Err(format!("Invalid coefficient `{}`", coefficient)),
+    };
+    let degree = Exponents::with_width(exponents, ring.width).map_err(|_| PolyError::ExponentOverflow.to_string())?;
+    Ok(Monomial { coefficient, degree, ring: Arc::clone(ring) })
+}
+
+fn polynomial<F: Coefficient>(terms: Vec<TermRepr>, ring: &Arc<Ring<F>>) -> Result<Polynomial<F>, String> {
+    let terms: Result<Vec<Monomial<F>>, String> = terms.iter()
+        .map(|t| term(&t.coefficient, &t.exponents, ring))
+        .collect();
+    Ok(Polynomial::from_terms(terms?, ring))
+}
+
+impl<F: Coefficient<Domain = ()>> Serialize for Ring<F> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        ring_repr(self).map_err(S::Error::custom)?.serialize(s)
+    }
+}
+
+impl<'de, F: Coefficient<Domain = ()>> Deserialize<'de> for Ring<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        ring(RingRepr::deserialize(d)?).map_err(D::Error::custom)
+    }
+}
+
+impl<F: Coefficient<Domain = ()>> Serialize for Monomial<F> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let t = term_repr(self);
+        MonomialRepr {
+            ring: ring_repr(&self.ring).map_err(S::Error::custom)?,
+            coefficient: t.coefficient,
+            exponents: t.exponents,
+        }.serialize(s)
+    }
+}
+
+impl<'de, F: Coefficient<Domain = ()>> Deserialize<'de> for Monomial<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let m = MonomialRepr::deserialize(d)?;
+        let ring = Arc::new(ring(m.ring).map_err(D::Error::custom)?);
+        term(&m.coefficient, &m.exponents, &ring).map_err(D::Error::custom)
+    }
+}
+
+impl<F: Coefficient<Domain = ()>> Serialize for Polynomial<F> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        PolynomialRepr {
+            ring: ring_repr(&self.ring).map_err(S::Error::custom)?,
+            terms: self.terms.iter().map(term_repr).collect(),
+        }.serialize(s)
+    }
+}
+
+impl<'de, F: Coefficient<Domain = ()>> Deserialize<'de> for Polynomial<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let p = PolynomialRepr::deserialize(d)?;
+        let ring = Arc::new(ring(p.ring).map_err(D::Error::custom)?);
+        polynomial(p.terms, &ring).map_err(D::Error::custom)
+    }
+}
+
+impl<F: Coefficient<Domain = ()>> Serialize for PolySet<F> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        same_rings(self).map_err(S::Error::custom)?;
+        let ring = match self.0.first() {
+            Some(f) => Some(ring_repr(&f.ring).map_err(S::Error::custom)?),
+            None => None,
+        };
+        PolySetRepr {
+            ring,
+            polynomials: self.0.iter().map(|f| f.terms.iter().map(term_repr).collect()).collect(),
+        }.serialize(s)
+    }
+}
+
+impl<'de, F: Coefficient<Domain = ()>> Deserialize<'de> for PolySet<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let ps = PolySetRepr::deserialize(d)?;
+        let ring = match ps.ring {
+            Some(r) => Arc::new(ring(r).map_err(D::Error::custom)?),
+            None if ps.polynomials.is_empty() => return Ok(PolySet(Vec::new())),
+            None => return Err(D::Error::custom("Polynomials are given without a ring")),
+        };
+        let polys: Result<Vec<Polynomial<F>>, String> = ps.polynomials.into_iter()
+            .map(|terms| polynomial(terms, &ring))
+            .collect();
+        Ok(PolySet(polys.map_err(D::Error::custom)?))
+    }
+}
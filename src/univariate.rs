@@ -0,0 +1,205 @@
+extern crate rug;
+
+use super::dense;
+use super::error::PolyError;
+use super::fields::{Coefficient, Field};
+use super::polynomials::*;
+
+use std::sync::Arc;
+use rug::Rational;
+
+// Dense univariate polynomials, stored as a vector of coefficients indexed
+//   by degree. For one variable this avoids the degree vectors and term
+//   merging of `Polynomial`.
+
+// Below this length products are computed directly, and above it with
+//   Karatsuba's algorithm.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DensePolynomial<F: Coefficient = Rational> {
+    // Coefficients from the constant term up, with no trailing zeros.
+    coeffs: Vec<F>,
+    domain: F::Domain,
+}
+
+fn add_into<F: Coefficient>(v: &mut [F], a: &[F]) {
+    for (x, y) in v.iter_mut().zip(a) {
+        *x = x.add(y);
+    }
+}
+
+fn sub_into<F: Coefficient>(v: &mut [F], a: &[F]) {
+    for (x, y) in v.iter_mut().zip(a) {
+        *x = x.sub(y);
+    }
+}
+
+// Product of two coefficient vectors of equal length n, as a vector of
+//   length 2n - 1.
+fn karatsuba<F: Coefficient>(a: &[F], b: &[F], d: &F::Domain) -> Vec<F> {
+    let n = a.len();
+    let mut v = vec![F::zero(d); 2 * n - 1];
+
+    if n < KARATSUBA_THRESHOLD {
+        for (i, x) in a.iter().enumerate() {
+            if x.is_zero() {
+                continue;
+            }
+            for (j, y) in b.iter().enumerate() {
+                v[i + j] = v[i + j].add(&x.mul(y));
+            }
+        }
+        return v;
+    }
+
+    // a = a0 + x^m a1 and b = b0 + x^m b1, with a1 and b1 padded to the
+    //   length of a0 and b0.
+    let m = n.div_ceil(2);
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let mut a1 = a1.to_vec();
+    let mut b1 = b1.to_vec();
+    a1.resize(m, F::zero(d));
+    b1.resize(m, F::zero(d));
+
+    let z0 = karatsuba(a0, b0, d);
+    let z2 = karatsuba(&a1, &b1, d);
+
+    let mut sa = a0.to_vec();
+    let mut sb = b0.to_vec();
+    add_into(&mut sa, &a1);
+    add_into(&mut sb, &b1);
+    let mut z1 = karatsuba(&sa, &sb, d);
+    sub_into(&mut z1, &z0);
+    sub_into(&mut z1, &z2);
+
+    add_into(&mut v, &z0);
+    add_into(&mut v[m..], &z1);
+    // The padding only adds zeros past the end of the product.
+    let k = v.len() - 2 * m;
+    add_into(&mut v[2 * m..], &z2[..k]);
+    v
+}
+
+impl<F: Coefficient> DensePolynomial<F> {
+    // Builds a polynomial from its coefficients, constant term first.
+    pub fn new(coeffs: Vec<F>, domain: &F::Domain) -> Self {
+        DensePolynomial { coeffs: dense::trim(coeffs), domain: domain.clone() }
+    }
+
+    pub fn zero(domain: &F::Domain) -> Self {
+        DensePolynomial { coeffs: Vec::new(), domain: domain.clone() }
+    }
+
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    // The degree, or None for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    pub fn lead(&self) -> Option<&F> {
+        self.coeffs.last()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        DensePolynomial { coeffs: dense::add(&self.coeffs, &other.coeffs), domain: self.domain.clone() }
+    }
+
+    pub fn neg(&self) -> Self {
+        DensePolynomial { coeffs: dense::neg(&self.coeffs), domain: self.domain.clone() }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        DensePolynomial { coeffs: dense::sub(&self.coeffs, &other.coeffs), domain: self.domain.clone() }
+    }
+
+    pub fn scalar_mult(&self, c: &F) -> Self {
+        DensePolynomial { coeffs: dense::scale(&self.coeffs, c), domain: self.domain.clone() }
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return DensePolynomial::zero(&self.domain);
+        }
+
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let mut a = self.coeffs.clone();
+        let mut b = other.coeffs.clone();
+        a.resize(n, F::zero(&self.domain));
+        b.resize(n, F::zero(&self.domain));
+
+        let mut v = karatsuba(&a, &b, &self.domain);
+        v.truncate(self.coeffs.len() + other.coeffs.len() - 1);
+        DensePolynomial::new(v, &self.domain)
+    }
+
+    // Evaluates the polynomial at a point with Horner's rule.
+    pub fn eval(&self, x: &F) -> F {
+        self.coeffs.iter().rev().fold(F::zero(&self.domain), |y, c| y.mul(x).add(c))
+    }
+
+    // Converts a polynomial in a ring with a single variable.
+    pub fn from_poly(f: &Polynomial<F>) -> Option<Self> {
+        if f.ring.symbols.len() != 1 {
+            return None;
+        }
+
+        let n = f.terms.iter().map(|m| m.degree.get(0) as usize + 1).max().unwrap_or(0);
+        let mut coeffs = vec![F::zero(&f.ring.domain); n];
+        for m in f.terms.iter() {
+            let i = m.degree.get(0) as usize;
+            coeffs[i] = coeffs[i].add(&m.coefficient);
+        }
+        Some(DensePolynomial::new(coeffs, &f.ring.domain))
+    }
+
+    // Converts back into a polynomial in a ring with a single variable,
+    //   with its terms in that ring's ordering. Returns None if the degree
+    //   is too large for the ring's exponents.
+    pub fn to_poly(&self, ring: &Arc<Ring<F>>) -> Option<Polynomial<F>> {
+        if ring.symbols.len() != 1 {
+            return None;
+        }
+
+        let mut terms: Vec<Monomial<F>> = self.coeffs.iter().enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| Some(Monomial {
+                coefficient: c.clone(),
+                degree: Exponents::with_width(&[i as u64], ring.width).ok()?,
+                ring: Arc::clone(ring),
+            }))
+            .collect::<Option<_>>()?;
+        terms.sort_by(|a, b| b.cmp(a));
+
+        Some(Polynomial { length: terms.len(), terms, ring: Arc::clone(ring) })
+    }
+}
+
+impl<F: Field> DensePolynomial<F> {
+    // Euclidean division, returning the quotient and remainder.
+    pub fn divmod(&self, other: &Self) -> Result<(Self, Self), PolyError> {
+        if other.is_zero() {
+            return Err(PolyError::DivisionByZero);
+        }
+        let (q, r) = dense::divmod(&self.coeffs, &other.coeffs, &self.domain);
+        Ok((DensePolynomial::new(q, &self.domain), DensePolynomial::new(r, &self.domain)))
+    }
+
+    // Scales the polynomial so its leading coefficient is 1.
+    pub fn monic(&self) -> Self {
+        DensePolynomial { coeffs: dense::monic(&self.coeffs, &self.domain), domain: self.domain.clone() }
+    }
+
+    // Monic greatest common divisor, by Euclid's algorithm.
+    pub fn gcd(&self, other: &Self) -> Self {
+        DensePolynomial { coeffs: dense::gcd(&self.coeffs, &other.coeffs, &self.domain), domain: self.domain.clone() }
+    }
+}
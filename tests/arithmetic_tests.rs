@@ -0,0 +1,110 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use rug::{Integer, Rational};
+use std::sync::Arc;
+
+mod common;
+use common::ring;
+
+#[test]
+fn polynomial_operators() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^2y^0 + -1x^0y^1 + 2x^0y^0", &r).unwrap();
+    let g = Polynomial::from_string("1x^1y^0 + 1x^0y^0", &r).unwrap();
+
+    assert!(&f + &g == add_polys(&f, &g).unwrap());
+    assert!(&f - &g == sub_polys(&f, &g).unwrap());
+    assert!(&f * &g == mult_polys(&f, &g).unwrap());
+    assert!(-&f == scalar_mult(&f, Rational::from(-1)));
+    assert!(f.clone() + g.clone() == &f + &g);
+    assert!(f.clone() * &g == &f * g.clone());
+
+    let (q, rem) = divide_polys(&f, &g).unwrap();
+    assert!(&f / &g == q);
+    assert!(&f % &g == rem);
+    assert!(&(&q * &g) + &rem == f);
+
+    let mut h = f.clone();
+    h += &g;
+    h -= g.clone();
+    assert!(h == f);
+    h *= &g;
+    h /= &g;
+    assert!(h == f);
+    h %= &g;
+    assert!(h == rem);
+
+    // (x + y)^2 - (x - y)^2 = 4xy, with the monomials written by hand.
+    let x = Monomial::from_string("1x^1y^0", Arc::clone(&r)).unwrap();
+    let y = Monomial::from_string("1x^0y^1", Arc::clone(&r)).unwrap();
+    let s = &x + &y;
+    let d = &x - &y;
+    assert!(&(&s * &s) - &(&d * &d) == Polynomial::from_monom(4 * &(&x * &y)));
+    assert!(&(&x * &y) / &y == x);
+    assert!(-(-x.clone()) == x);
+
+    let mut p = Polynomial::zero(&r);
+    p += &x;
+    p *= &y;
+    p -= x.clone() * y.clone();
+    assert!(p.terms.is_empty());
+}
+
+#[test]
+fn scalar_operators() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^2y^0 + -1/2x^0y^1", &r).unwrap();
+
+    let twice = scalar_mult(&f, Rational::from(2));
+    assert!(&f * 2 == twice);
+    assert!(2 * &f == twice);
+    assert!(2 * f.clone() == twice);
+    assert!(&f * Rational::from(2) == twice);
+    assert!(Rational::from(2) * &f == twice);
+    assert!(&twice / 2 == f);
+    assert!(&twice / Rational::from((2, 1)) == f);
+    assert!((&f % 3).terms.is_empty());
+    assert!((&f * Rational::new()).terms.is_empty());
+
+    let mut h = f.clone();
+    h *= -4i64;
+    h /= Rational::from(-4);
+    assert!(h == f);
+
+    let m = f.terms[0].clone();
+    assert!((&m * 3).coefficient == 3);
+    assert!((Rational::from((1, 2)) * &m).coefficient == (1, 2));
+    assert!((&m / 4).coefficient == (1, 4));
+
+    // Integer scalars are mapped into other coefficient fields.
+    let p = Arc::new((*r).clone().over::<Zp>(7));
+    let g = Polynomial::from_string("1x^1y^0 + 3x^0y^0", &p).unwrap();
+    assert!(&g * 9 == scalar_mult(&g, Zp::new(2, 7)));
+    assert!(&g * -1 == -&g);
+    assert!((&g * 7).terms.is_empty());
+
+    let z = Arc::new((*r).clone().over::<Integer>(()));
+    let g = Polynomial::from_string("2x^1y^0 + 3x^0y^0", &z).unwrap();
+    assert!(Integer::from(3) * &g == &g * 3);
+    assert!(Integer::from_integer(&Integer::from(-12), &()) == -12);
+    assert!(Zp::from_integer(&Integer::from(-12), &7) == Zp::new(2, 7));
+    assert!(GaussianRational::from_integer(&Integer::from(-12), &()) == GaussianRational::parse("-12", &()).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Operands belong to different rings")]
+fn ring_mismatch_panics() {
+    let f = Polynomial::from_string("1x^1y^0", &ring(&["x", "y"], MonomialOrdering::DegRevLex)).unwrap();
+    let g = Polynomial::from_string("1x^1z^0", &ring(&["x", "z"], MonomialOrdering::DegRevLex)).unwrap();
+    let _ = &f + &g;
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn division_by_zero_panics() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0", &r).unwrap();
+    let _ = &f / &Polynomial::zero(&r);
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use polynomial_operations::fields::Coefficient;
+use polynomial_operations::polynomials::{ExponentWidth, MonomialOrdering, Ring};
+
+use std::sync::Arc;
+
+// Rings shared by the tests. Each test file uses only some of these.
+
+// A ring over the rationals in the given variables.
+pub fn ring(symbols: &[&str], ord: MonomialOrdering) -> Arc<Ring> {
+    ring_over(symbols, ord, ())
+}
+
+// A ring over any coefficient field, given the field's domain.
+pub fn ring_over<F: Coefficient>(symbols: &[&str], ord: MonomialOrdering, domain: F::Domain) -> Arc<Ring<F>> {
+    let symbols = symbols.iter().map(|s| s.to_string()).collect();
+    Arc::new(Ring::new(symbols, ord).over(domain))
+}
+
+// A ring over the rationals whose exponents have the given width.
+pub fn ring_with_width(symbols: &[&str], ord: MonomialOrdering, width: ExponentWidth) -> Arc<Ring> {
+    let symbols = symbols.iter().map(|s| s.to_string()).collect();
+    Arc::new(Ring::new(symbols, ord).with_width(width))
+}
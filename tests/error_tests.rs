@@ -0,0 +1,64 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::modular::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use std::sync::Arc;
+
+mod common;
+use common::ring;
+
+#[test]
+fn ring_mismatch() {
+    let r1 = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let r2 = ring(&["x", "z"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0 + 1x^0y^1", &r1).unwrap();
+    let g = Polynomial::from_string("1x^1z^0 + 1x^0z^1", &r2).unwrap();
+
+    assert!(add_polys(&f, &g) == Err(PolyError::RingMismatch));
+    assert!(sub_polys(&f, &g) == Err(PolyError::RingMismatch));
+    assert!(mult_polys(&f, &g) == Err(PolyError::RingMismatch));
+    assert!(mult_monoms(&f.terms[0], &g.terms[0]) == Err(PolyError::RingMismatch));
+    assert!(divide_polys(&f, &g) == Err(PolyError::RingMismatch));
+    assert!(divide_poly_set(&f, &mut PolySet(vec![g.clone()])).is_err());
+    assert!(s_poly(&f, &g) == Err(PolyError::RingMismatch));
+    assert!(grobner_basis(&PolySet(vec![f.clone(), g.clone()])) == Err(PolyError::RingMismatch));
+    assert!(grobner_basis_modular(&PolySet(vec![f.clone(), g.clone()])) == Err(PolyError::RingMismatch));
+
+    // Rings built separately with the same variables, ordering and field
+    //   are interchangeable.
+    let h = Polynomial::from_string("1x^1y^0", &ring(&["x", "y"], MonomialOrdering::DegRevLex)).unwrap();
+    assert!(add_polys(&f, &h).is_ok());
+
+}
+
+#[test]
+fn zero_polynomials() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^2y^0 + 1x^0y^1", &r).unwrap();
+    let zero = sub_polys(&f, &f).unwrap();
+
+    assert!(zero.lt().is_none());
+    assert!(zero.lm().is_none());
+    assert!(f.lt().is_some());
+    assert!(divide_polys(&f, &zero) == Err(PolyError::DivisionByZero));
+    assert!(s_poly(&f, &zero).unwrap() == f);
+    assert!(grobner_basis(&PolySet(vec![zero.clone()])).unwrap().0.is_empty());
+
+    let c = Monomial::from_string("0x^0y^0", Arc::clone(&r)).unwrap();
+    assert!(divide_monoms(&f.terms[0], &c) == Err(PolyError::DivisionByZero));
+}
+
+#[test]
+fn invalid_arguments() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0 + 1x^0y^1", &r).unwrap();
+
+    assert!(eliminate(&PolySet(vec![f.clone()]), &["t"]) == Err(PolyError::UnknownVariable(String::from("t"))));
+    assert!(divide_monoms(&f.terms[1], &f.terms[0]) == Err(PolyError::NotDivisible));
+
+    let mut m = f.terms[0].clone();
+    m.degree = Exponents::new(&[1, 0, 0]);
+    assert!(gcd(&m, &f.terms[0]) == Err(PolyError::LengthMismatch));
+    assert!(lcm(&m, &f.terms[1]) == Err(PolyError::LengthMismatch));
+}
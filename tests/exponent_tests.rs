@@ -0,0 +1,178 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::exponents::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::poly;
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+mod common;
+use common::ring_with_width;
+
+fn vectors(n: usize) -> Vec<Vec<u16>> {
+    // A spread of exponent vectors, including the largest exponent.
+    (0..40u32)
+        .map(|k| (0..n).map(|i| ((k * 7 + i as u32 * 13) % 5) as u16).collect())
+        .chain([vec![MAX_EXPONENT; n], vec![0; n]])
+        .collect()
+}
+
+fn wide(v: &[u16]) -> Vec<u64> {
+    v.iter().map(|d| u64::from(*d)).collect()
+}
+
+#[test]
+fn pack_and_unpack() {
+    for n in [1, 3, 8, 11] {
+        for v in vectors(n) {
+            let e = Exponents::new(&v);
+            assert!(e.to_vec() == wide(&v));
+            assert!(e.len() == n);
+            assert!(e.total() == v.iter().map(|d| u64::from(*d)).sum::<u64>());
+        }
+    }
+}
+
+#[test]
+fn packed_arithmetic() {
+    for n in [2, 5, 9] {
+        let vs = vectors(n);
+        for a in vs.iter().filter(|v| v[0] < MAX_EXPONENT) {
+            for b in vs.iter().filter(|v| v[0] < MAX_EXPONENT) {
+                let (ea, eb) = (Exponents::new(a), Exponents::new(b));
+
+                let divides = a.iter().zip(b).all(|(x, y)| x <= y);
+                assert!(ea.divides(&eb) == divides);
+                if divides {
+                    let q: Vec<u16> = b.iter().zip(a).map(|(x, y)| x - y).collect();
+                    assert!(eb.checked_div(&ea).unwrap() == q);
+                }
+
+                let lcm: Vec<u16> = a.iter().zip(b).map(|(x, y)| *x.max(y)).collect();
+                let gcd: Vec<u16> = a.iter().zip(b).map(|(x, y)| *x.min(y)).collect();
+                assert!(ea.lcm(&eb) == lcm);
+                assert!(ea.gcd(&eb) == gcd);
+                assert!(ea.lcm(&eb).total() == lcm.iter().map(|d| u64::from(*d)).sum::<u64>());
+            }
+        }
+    }
+
+    let a = Exponents::new(&[2, 0, 7]);
+    let b = Exponents::new(&[1, 3, 0]);
+    assert!(a.checked_mul(&b).unwrap() == vec![3, 3, 7]);
+    assert!(a.checked_mul(&b).unwrap().total() == 13);
+}
+
+#[test]
+fn exponent_overflow() {
+    let a = Exponents::with_width(&[0x7FFF, 0], ExponentWidth::U16).unwrap();
+    let b = Exponents::new(&[1, 0]).to_width(ExponentWidth::U16).unwrap();
+    assert!(a.checked_mul(&b) == Err(ExponentError::Overflow));
+
+    // The default lanes take any u16.
+    let c = Exponents::new(&[MAX_EXPONENT, MAX_EXPONENT]);
+    assert!(c.checked_mul(&c).unwrap().to_vec() == vec![131070, 131070]);
+    assert!(Exponents::from(vec![MAX_EXPONENT]).width() == ExponentWidth::default());
+}
+
+#[test]
+fn packed_orderings_match() {
+    for n in [3, 9] {
+        let vs = vectors(n);
+        for ord in [MonomialOrdering::Lex, MonomialOrdering::DegLex, MonomialOrdering::DegRevLex] {
+            for a in vs.iter() {
+                for b in vs.iter() {
+                    let packed = ord.compare_exponents(&Exponents::new(a), &Exponents::new(b));
+                    assert!(packed == ord.compare(&wide(a), &wide(b)));
+                }
+            }
+        }
+    }
+
+    let a = Exponents::new(&[1, 0, 1]);
+    let b = Exponents::new(&[0, 2, 0]);
+    assert!(MonomialOrdering::DegRevLex.compare_exponents(&a, &b) == Ordering::Less);
+    assert!(MonomialOrdering::DegLex.compare_exponents(&a, &b) == Ordering::Greater);
+}
+
+#[test]
+fn exponent_widths() {
+    let widths = [ExponentWidth::U8, ExponentWidth::U16, ExponentWidth::U32, ExponentWidth::U64];
+    for w in widths {
+        for n in [1, 3, 8, 17] {
+            let v: Vec<u64> = (0..n as u64).map(|i| (i * 5 + 1) % (w.max() + 1)).chain([w.max()]).collect();
+            let e = Exponents::with_width(&v, w).unwrap();
+            assert!(e.to_vec() == v);
+            assert!(e.width() == w);
+
+            // A zero vector and the same vector in the widest lanes
+            //   multiply and divide back to it.
+            let z = Exponents::zero_with_width(n + 1, ExponentWidth::U64);
+            assert!(e.checked_mul(&z).unwrap() == e);
+            assert!(e.checked_div(&e).unwrap() == z);
+            assert!(e.to_width(ExponentWidth::U64).unwrap() == e);
+
+            let one = Exponents::with_width(&vec![1; n + 1], w).unwrap();
+            assert!(e.checked_mul(&one) == Err(ExponentError::Overflow));
+        }
+
+        let v = vec![w.max() + 1];
+        assert!(Exponents::with_width(&v, w).is_err());
+    }
+
+    let a = Exponents::with_width(&[200, 3], ExponentWidth::U32).unwrap();
+    let b = Exponents::with_width(&[100, 4], ExponentWidth::U8).unwrap();
+    assert!(b.to_width(ExponentWidth::U8).unwrap() == b);
+    assert!(a.to_width(ExponentWidth::U8) == Err(ExponentError::Overflow));
+    assert!(a.checked_div(&b) == Err(ExponentError::NotDivisible));
+    assert!(a.checked_mul(&b).unwrap().to_vec() == vec![300, 7]);
+    assert!(a.lcm(&b).to_vec() == vec![200, 4]);
+    assert!(MonomialOrdering::Lex.compare_exponents(&a, &b) == Ordering::Greater);
+}
+
+#[test]
+fn division_not_dividing() {
+    let a = Exponents::new(&[2, 0, 7]);
+    let b = Exponents::new(&[1, 3, 0]);
+    assert!(a.checked_div(&b) == Err(ExponentError::NotDivisible));
+}
+
+#[test]
+fn ring_exponent_widths() {
+    let ring = ring_with_width(&["x", "y"], MonomialOrdering::DegRevLex, ExponentWidth::U8);
+
+    let a = Monomial::from_string("3x^100y^2", Arc::clone(&ring)).unwrap();
+    let b = Monomial::from_string("1/2x^27y^0", Arc::clone(&ring)).unwrap();
+    assert!(a.degree.width() == ExponentWidth::U8);
+    assert!(mult_monoms(&a, &b).unwrap().degree == vec![127, 2]);
+    assert!(mult_monoms(&a, &a) == Err(PolyError::ExponentOverflow));
+    assert!(divide_monoms(&a, &b).unwrap().degree == vec![73, 2]);
+    assert!(divide_monoms(&b, &a) == Err(PolyError::NotDivisible));
+
+    assert!(matches!(Monomial::from_string("1x^128y^0", Arc::clone(&ring)), Err(MonomError::ExponentOverflow)));
+    assert!(matches!(Monomial::from_string("1x^-1y^0", Arc::clone(&ring)), Err(MonomError::InvalidExponent)));
+    assert!(matches!(Monomial::from_string("1x^99999999999999999999y^0", Arc::clone(&ring)), Err(MonomError::InvalidExponent)));
+
+    let ring = Arc::new((*ring).clone().with_width(ExponentWidth::U64));
+    let f = Polynomial::from_string("1x^4000000000y^1 + 1x^0y^0", &ring).unwrap();
+    let g = mult_polys(&f, &f).unwrap();
+    assert!(g.terms[0].degree.to_vec() == vec![8000000000, 2]);
+}
+
+#[test]
+fn basis_near_the_width_limit() {
+    let r = ring_with_width(&["x", "y"], MonomialOrdering::DegRevLex, ExponentWidth::U16);
+
+    // The products of these leading monomials do not fit in the lanes,
+    //   though nothing the algorithm needs is that large.
+    let f = poly!(r, "x^20000");
+    let g = poly!(r, "x^20000*y + y^2");
+    let gb = grobner_basis(&PolySet(vec![f.clone(), g])).unwrap();
+    assert!(gb.0.len() == 2 && gb.0.contains(&f) && gb.0.contains(&poly!(r, "y^2")));
+
+    let f = poly!(r, "x^30000 + y");
+    let g = poly!(r, "y^30000");
+    let gb = grobner_basis(&PolySet(vec![f.clone(), g.clone()])).unwrap();
+    assert!(gb.0.len() == 2 && gb.0.contains(&f) && gb.0.contains(&g));
+}
@@ -0,0 +1,89 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::operations::*;
+
+use std::fmt;
+
+mod common;
+use common::ring_over;
+
+// Integers mod 5, defined the way a downstream crate would add its own
+//   coefficient field.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct F5(u8);
+
+impl fmt::Display for F5 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Coefficient for F5 {
+    type Domain = ();
+
+    fn zero(_: &()) -> Self {
+        F5(0)
+    }
+    fn one(_: &()) -> Self {
+        F5(1)
+    }
+    fn parse(s: &str, _: &()) -> Option<Self> {
+        s.parse::<i64>().ok().map(|n| F5(n.rem_euclid(5) as u8))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+    fn add(&self, other: &Self) -> Self {
+        F5((self.0 + other.0) % 5)
+    }
+    fn sub(&self, other: &Self) -> Self {
+        F5((self.0 + 5 - other.0) % 5)
+    }
+    fn mul(&self, other: &Self) -> Self {
+        F5((self.0 * other.0) % 5)
+    }
+    fn neg(&self) -> Self {
+        F5((5 - self.0) % 5)
+    }
+}
+
+impl Field for F5 {
+    fn checked_inv(&self) -> Option<Self> {
+        (1..5).find(|i| (self.0 * i) % 5 == 1).map(F5)
+    }
+}
+
+#[test]
+fn generic_field_arithmetic() {
+    let ring = ring_over::<F5>(&["x", "y"], MonomialOrdering::DegLex, ());
+
+    let p1 = Polynomial::from_string("2x^1y^0 + 3x^0y^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("3x^1y^0 + 2x^0y^1", &ring).unwrap();
+
+    // 2 + 3 = 0 mod 5, so both terms cancel.
+    assert!(add_polys(&p1, &p2).unwrap().terms.is_empty());
+
+    let p3 = mult_polys(&p1, &p2).unwrap();
+    let p4 = Polynomial::from_string("1x^2y^0 + 3x^1y^1 + 1x^0y^2", &ring).unwrap();
+
+    assert!(p3 == p4);
+}
+
+#[test]
+fn generic_field_basis() {
+    let ring = ring_over::<F5>(&["x", "y"], MonomialOrdering::DegLex, ());
+
+    let p1 = Polynomial::from_string("2x^1y^0 + 1x^0y^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^0y^2 + 4x^0y^0", &ring).unwrap();
+
+    let b = grobner_basis(&PolySet(vec![p1, p2])).unwrap();
+
+    // 1/2 = 3 mod 5, so the reduced basis element is x + 3y.
+    let g1 = Polynomial::from_string("1x^1y^0 + 3x^0y^1", &ring).unwrap();
+    let g2 = Polynomial::from_string("1x^0y^2 + 4x^0y^0", &ring).unwrap();
+
+    assert!(b.0.len() == 2);
+    assert!(b.0.contains(&g1));
+    assert!(b.0.contains(&g2));
+}
@@ -0,0 +1,91 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::galois::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::operations::*;
+
+mod common;
+use common::ring_over;
+
+#[test]
+fn gf4_arithmetic() {
+    // GF(4) = GF(2)[a] / (a^2 + a + 1)
+    let gf = GfContext::new(2, vec![1, 1, 1], "a").unwrap();
+    let a = Gf::generator(&gf);
+    let one = Gf::one(&gf);
+
+    assert!(gf.order() == 4);
+    assert!(a.mul(&a) == a.add(&one));
+    assert!(a.mul(&a).mul(&a) == one);
+    assert!(a.checked_inv().unwrap() == a.add(&one));
+    assert!(a.add(&a).is_zero());
+    assert!(a.div(&a) == one);
+}
+
+#[test]
+fn gf_inverses() {
+    // GF(5^3) = GF(5)[a] / (a^3 + 3a + 3)
+    let gf = GfContext::new(5, vec![3, 3, 0, 1], "a").unwrap();
+
+    for c0 in 0..5 {
+        for c1 in 0..5 {
+            for c2 in 0..5 {
+                let x = Gf::from_coeffs(vec![c0, c1, c2], &gf);
+                match x.checked_inv() {
+                    Some(y) => assert!(x.mul(&y) == Gf::one(&gf)),
+                    None => assert!(x.is_zero()),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn modulus_checks() {
+    assert!(GfContext::new(2, vec![1, 1, 0, 1], "a").is_ok());
+    // a^2 + 1 = (a + 1)^2 over GF(2).
+    assert!(GfContext::new(2, vec![1, 0, 1], "a").is_err());
+    // a^4 + 4 = (a^2 + 2a + 2)(a^2 - 2a + 2) has no roots mod 5 but
+    //   still factors.
+    assert!(GfContext::new(5, vec![4, 0, 0, 0, 1], "a").is_err());
+    assert!(GfContext::new(4, vec![1, 1, 1], "a").is_err());
+    assert!(GfContext::new(2, vec![1], "a").is_err());
+}
+
+#[test]
+fn gf_parse_and_print() {
+    let gf = GfContext::new(2, vec![1, 1, 0, 1], "a").unwrap();
+
+    let x = Gf::parse("a^2 + a + 1", &gf).unwrap();
+    assert!(x.coeffs() == [1, 1, 1]);
+    assert!(x.to_string() == "(a^2+a+1)");
+
+    // a^3 = a + 1, and subtraction is addition in characteristic 2.
+    assert!(Gf::parse("a^3", &gf).unwrap() == Gf::parse("a - 1", &gf).unwrap());
+    assert!(Gf::parse("3*a", &gf).unwrap() == Gf::generator(&gf));
+    assert!(Gf::parse("b", &gf).is_none());
+}
+
+#[test]
+fn gf_polynomials() {
+    let gf = GfContext::new(2, vec![1, 1, 1], "a").unwrap();
+    let ring = ring_over::<Gf>(&["x", "y"], MonomialOrdering::Lex, gf);
+
+    let p1 = Polynomial::from_string("(a)x^1y^0 + 1x^0y^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^0 + (a+1)x^0y^1", &ring).unwrap();
+
+    // p1 = a * p2, since a * (a + 1) = 1.
+    let (q, r) = divide_polys(&p1, &p2).unwrap();
+    assert!(r.terms.is_empty());
+    assert!(q.to_string() == "(a)");
+
+    let p3 = Polynomial::from_string("1x^0y^2 + (a)x^0y^0", &ring).unwrap();
+    let b = grobner_basis(&PolySet(vec![p1.clone(), p3.clone()])).unwrap();
+
+    let g1 = Polynomial::from_string("1x^1y^0 + (a+1)x^0y^1", &ring).unwrap();
+    assert!(b.0.len() == 2);
+    assert!(b.0.contains(&g1));
+    assert!(b.0.contains(&p3));
+
+    let s = mult_polys(&p1, &p1).unwrap().to_string();
+    assert!(Polynomial::from_string(&s, &ring).unwrap() == mult_polys(&p1, &p1).unwrap());
+}
@@ -0,0 +1,71 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use rug::Rational;
+
+use std::sync::Arc;
+
+mod common;
+use common::ring_over;
+
+fn z(re: (i32, i32), im: (i32, i32)) -> GaussianRational {
+    GaussianRational::new(Rational::from(re), Rational::from(im))
+}
+
+#[test]
+fn gaussian_arithmetic() {
+    let i = GaussianRational::i();
+    let one = GaussianRational::one(&());
+
+    assert!(i.mul(&i) == one.neg());
+    assert!(i.checked_inv().unwrap() == i.neg());
+
+    // (1 + 2i)(3 - i) = 5 + 5i
+    let a = z((1, 1), (2, 1));
+    let b = z((3, 1), (-1, 1));
+    assert!(a.mul(&b) == z((5, 1), (5, 1)));
+    assert!(a.mul(&b).div(&b) == a);
+    assert!(a.mul(&a.conj()) == z((5, 1), (0, 1)));
+    assert!(a.checked_inv().unwrap() == z((1, 5), (-2, 5)));
+    assert!(GaussianRational::zero(&()).checked_inv().is_none());
+}
+
+#[test]
+fn gaussian_parse_and_print() {
+    let ring = ring_over::<GaussianRational>(&["x"], MonomialOrdering::Lex, ());
+
+    let m = Monomial::from_string("(3/2+1/4i)x^2", Arc::clone(&ring)).unwrap();
+    assert!(m.coefficient == z((3, 2), (1, 4)));
+    assert!(m.to_string() == "(3/2+1/4i)x^2");
+
+    let p = Polynomial::from_string("2x^0 + (-i)x^1 + (1 - 2*i)x^0", &ring).unwrap();
+    assert!(p.terms.len() == 2);
+    assert!(p.terms[0].to_string() == "(-i)x^1");
+    assert!(p.terms[1].coefficient == z((3, 1), (-2, 1)));
+    assert!(p.terms[1].to_string() == "(3-2i)");
+
+    assert!(GaussianRational::parse("i", &()).unwrap() == GaussianRational::i());
+    assert!(GaussianRational::parse("2 + 3j", &()).is_none());
+    assert!(z((-1, 2), (0, 1)).to_string() == "-1/2");
+}
+
+#[test]
+fn gaussian_basis() {
+    let ring = ring_over::<GaussianRational>(&["x", "y"], MonomialOrdering::Lex, ());
+
+    // x^2 + 1 = (x - i)(x + i), and y = ix picks the root x = i when
+    //   combined with y + 1.
+    let p1 = Polynomial::from_string("1x^2y^0 + 1x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^0y^1 + (-i)x^1y^0", &ring).unwrap();
+    let p3 = Polynomial::from_string("1x^0y^1 + 1x^0y^0", &ring).unwrap();
+
+    let g = grobner_basis(&PolySet(vec![p1, p2, p3])).unwrap();
+
+    let x = Polynomial::from_string("1x^1y^0 + (-i)x^0y^0", &ring).unwrap();
+    let y = Polynomial::from_string("1x^0y^1 + 1x^0y^0", &ring).unwrap();
+
+    assert!(g.0.len() == 2);
+    assert!(g.0.contains(&x));
+    assert!(g.0.contains(&y));
+}
@@ -0,0 +1,58 @@
+use polynomial_operations::geobucket::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+mod common;
+use common::ring;
+
+fn power(f: &Polynomial, n: usize) -> Polynomial {
+    (1..n).fold(f.clone(), |p, _| mult_polys(&p, f).unwrap())
+}
+
+#[test]
+fn geobucket_accumulates() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0z^0 + 1x^0y^1z^0 + 1x^0y^0z^1 + 1x^0y^0z^0", &ring).unwrap();
+    let g = Polynomial::from_string("1x^1y^0z^0 + -1x^0y^1z^0", &ring).unwrap();
+
+    // f^6 + f^5 g - x f^5 + y f^5 = f^6
+    let mut b = Geobucket::from_poly(&power(&f, 6));
+    b.add(&mult_polys(&power(&f, 5), &g).unwrap()).unwrap();
+    b.sub_mult(&Polynomial::from_string("1x^1y^0z^0", &ring).unwrap().terms[0], &power(&f, 5).terms).unwrap();
+    b.sub_mult(&Polynomial::from_string("-1x^0y^1z^0", &ring).unwrap().terms[0], &power(&f, 5).terms).unwrap();
+
+    assert!(!b.is_zero());
+    let lead = b.pop_lead().unwrap();
+    assert!(lead == power(&f, 6).terms[0]);
+
+    let rest = b.into_poly();
+    assert!(rest == sub_polys(&power(&f, 6), &power(&f, 6).lt().unwrap()).unwrap());
+
+    let mut b = Geobucket::from_poly(&f);
+    b.add(&scalar_mult(&f, (-1).into())).unwrap();
+    assert!(b.is_zero());
+    assert!(b.into_poly().terms.is_empty());
+}
+
+#[test]
+fn division_identity() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0z^0 + 2x^0y^1z^0 + -1x^0y^0z^1 + 3x^0y^0z^0", &ring).unwrap();
+    let f = power(&f, 7);
+    let mut g = PolySet(vec![
+        Polynomial::from_string("1x^2y^0z^0 + -1x^0y^1z^1 + 2x^0y^0z^0", &ring).unwrap(),
+        Polynomial::from_string("2x^0y^2z^0 + 1x^1y^0z^0", &ring).unwrap(),
+        Polynomial::from_string("1x^1y^1z^1 + -3x^0y^0z^1", &ring).unwrap(),
+    ]);
+
+    let (q, r) = divide_poly_set(&f, &mut g).unwrap();
+
+    // f = q1 g1 + q2 g2 + q3 g3 + r, and no term of r is divisible by a
+    //   leading term of g.
+    let mut h = r.clone();
+    for (qi, gi) in q.0.iter().zip(g.0.iter()) {
+        h = add_polys(&h, &mult_polys(qi, gi).unwrap()).unwrap();
+    }
+    assert!(h == f);
+    assert!(r.terms.iter().all(|m| g.0.iter().all(|p| !monom_divides(&p.terms[0], m))));
+}
@@ -0,0 +1,103 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::poly;
+
+use rug::Integer;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+mod common;
+use common::ring;
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut h = DefaultHasher::new();
+    t.hash(&mut h);
+    h.finish()
+}
+
+#[test]
+fn hash_agrees_with_equality() {
+    // Equal rings behind different pointers.
+    let f = poly!(ring(&["x", "y"], MonomialOrdering::DegRevLex), "x^2 - 3/2*x*y + 1");
+    let g = poly!(ring(&["x", "y"], MonomialOrdering::DegRevLex), "1 - 3/2*y*x + x^2");
+    assert!(f == g && hash(&f) == hash(&g));
+    assert!(f.terms[1] == g.terms[1] && hash(&f.terms[1]) == hash(&g.terms[1]));
+
+    let wide = Exponents::with_width(&[1, 2], ExponentWidth::U64).unwrap();
+    assert!(wide == Exponents::new(&[1, 2]) && hash(&wide) == hash(&Exponents::new(&[1, 2])));
+    assert!(Exponents::new(&[1, 2]) == Exponents::new(&[1, 2, 0]));
+    assert!(hash(&Exponents::new(&[1, 2])) == hash(&Exponents::new(&[1, 2, 0])));
+    assert!(hash(&Exponents::new(&[0, 3])) != hash(&Exponents::new(&[3])));
+}
+
+#[test]
+fn equality_includes_the_ring() {
+    let f = poly!(ring(&["x", "y"], MonomialOrdering::Lex), "x + y^2");
+    let g = poly!(ring(&["x", "y"], MonomialOrdering::DegRevLex), "x + y^2");
+    let h = poly!(ring(&["a", "b"], MonomialOrdering::Lex), "a + b^2");
+    assert!(f != g && f != h);
+    assert!(f.terms[0] != h.terms[0]);
+
+    let set: HashSet<Polynomial> = vec![f.clone(), g, h, f].into_iter().collect();
+    assert!(set.len() == 3);
+}
+
+#[test]
+fn polynomials_as_keys() {
+    let r = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+    let ps = PolySet(vec![poly!(r, "x^2 - y"), poly!(r, "x*y - z"), poly!(r, "y^2 - x*z")]);
+
+    // Scaled copies of the same S-polynomial collapse once made monic.
+    let mut seen = HashSet::new();
+    for f in &ps.0 {
+        for g in &ps.0 {
+            if f != g {
+                seen.insert(s_poly(f, g).unwrap().monic());
+            }
+        }
+    }
+    assert!(seen.len() == 3);
+
+    let gb = grobner_basis(&ps).unwrap();
+    let mut cache: HashMap<Polynomial, Polynomial> = HashMap::new();
+    let f = poly!(r, "x^3*y + z^2");
+    let nf = divide_poly_set(&f, &mut gb.clone()).unwrap().1;
+    cache.insert(f.clone(), nf.clone());
+    assert!(cache.get(&poly!(r, "z^2 + y*x^3")) == Some(&nf));
+
+    let sets: HashSet<PolySet> = vec![gb.clone(), gb.clone(), ps].into_iter().collect();
+    assert!(sets.len() == 2);
+
+    // Other coefficient fields hash too.
+    let zp = Arc::new(ring(&["x"], MonomialOrdering::Lex).as_ref().clone().over::<Zp>(7));
+    let qi = Arc::new(ring(&["x"], MonomialOrdering::Lex).as_ref().clone().over::<GaussianRational>(()));
+    assert!(hash(&poly!(zp, "8x + 2")) == hash(&poly!(zp, "x + 9")));
+    assert!(hash(&poly!(qi, "i*x + 1")) == hash(&poly!(qi, "(0 + i)x + 1")));
+}
+
+#[test]
+fn canonical_forms() {
+    let r = ring(&["x", "y"], MonomialOrdering::Lex);
+    let f = poly!(r, "-2/3x^2 + 4/9*y - 2");
+    assert!(f.monic() == poly!(r, "x^2 - 2/3*y + 3"));
+    assert!(f.primitive() == poly!(r, "3x^2 - 2y + 9"));
+    assert!(poly!(r, "1/2x + 1/3").primitive() == poly!(r, "3x + 2"));
+    assert!(f.primitive().primitive() == f.primitive());
+    assert!(Polynomial::zero(&r).monic() == Polynomial::zero(&r));
+    assert!(Polynomial::zero(&r).primitive() == Polynomial::zero(&r));
+
+    let z = Arc::new(ring(&["x", "y"], MonomialOrdering::Lex).as_ref().clone().over::<Integer>(()));
+    assert!(poly!(z, "-6x^2 + 4y - 18").primitive() == poly!(z, "3x^2 - 2y + 9"));
+    assert!(poly!(z, "5").primitive() == poly!(z, "1"));
+
+    // Generators that differ by scalars compare equal once canonical.
+    let a = PolySet(vec![poly!(r, "2x - 4y"), poly!(r, "-y^2 + 1/2")]);
+    let b = PolySet(vec![poly!(r, "-1/2x + y"), poly!(r, "3y^2 - 3/2")]);
+    assert!(a != b);
+    assert!(a.monic() == b.monic());
+    assert!(a.primitive() == b.primitive());
+    assert!(a.primitive() == PolySet(vec![poly!(r, "x - 2y"), poly!(r, "2y^2 - 1")]));
+}
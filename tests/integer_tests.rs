@@ -0,0 +1,85 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::integers::*;
+use polynomial_operations::polynomials::*;
+
+use rug::Integer;
+
+mod common;
+use common::ring_over;
+
+#[test]
+fn degenerate_prime() {
+    let ring = ring_over::<Integer>(&["x", "y"], MonomialOrdering::DegLex, ());
+
+    // Over Q this ideal is the whole ring, but over Z it only
+    //   degenerates to x^2 + 1 = 0 at the prime 3.
+    let p1 = Polynomial::from_string("3x^1y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^2y^0 + 1x^0y^0", &ring).unwrap();
+
+    let g = strong_grobner_basis(&PolySet(vec![p1, p2])).unwrap();
+
+    let g1 = Polynomial::from_string("1x^2y^0 + 1x^0y^0", &ring).unwrap();
+    let g2 = Polynomial::from_string("3x^0y^0", &ring).unwrap();
+
+    assert!(g.0.len() == 2);
+    assert!(g.0.contains(&g1));
+    assert!(g.0.contains(&g2));
+}
+
+#[test]
+fn gcd_polynomials() {
+    let ring = ring_over::<Integer>(&["x", "y"], MonomialOrdering::DegLex, ());
+
+    // Neither 4x nor 6x divides the other, but 2x is in the ideal.
+    let p1 = Polynomial::from_string("4x^1y^0 + 1x^0y^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("6x^1y^0", &ring).unwrap();
+
+    let g = strong_grobner_basis(&PolySet(vec![p1, p2])).unwrap();
+
+    for p in g.0.iter() {
+        assert!(p.terms[0].coefficient > 0);
+    }
+    assert!(g.0.iter().any(|p| p.terms[0].coefficient == 2 && p.terms[0].degree == vec![1, 0]));
+}
+
+#[test]
+fn membership() {
+    let ring = ring_over::<Integer>(&["x", "y"], MonomialOrdering::DegLex, ());
+
+    let p1 = Polynomial::from_string("2x^1y^0 + -1x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("3x^0y^1 + -1x^0y^0", &ring).unwrap();
+    let ideal = PolySet(vec![p1, p2]);
+
+    // 6xy - 1 = 3y(2x - 1) + (3y - 1)
+    let f = Polynomial::from_string("6x^1y^1 + -1x^0y^0", &ring).unwrap();
+    assert!(in_ideal(&f, &ideal).unwrap());
+
+    // xy - 1 isn't in the ideal, since 6xy - 6 would leave 5 behind.
+    let f = Polynomial::from_string("1x^1y^1 + -1x^0y^0", &ring).unwrap();
+    assert!(!in_ideal(&f, &ideal).unwrap());
+
+    let f = Polynomial::from_string("1x^0y^0", &ring).unwrap();
+    assert!(!in_ideal(&f, &ideal).unwrap());
+
+    let ring = ring_over::<Integer>(&["x"], MonomialOrdering::DegLex, ());
+    let p1 = Polynomial::from_string("3x^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^2 + 1x^0", &ring).unwrap();
+    let ideal = PolySet(vec![p1, p2]);
+
+    assert!(in_ideal(&Polynomial::from_string("6x^1 + 3x^0", &ring).unwrap(), &ideal).unwrap());
+    assert!(in_ideal(&Polynomial::from_string("1x^2 + 4x^0", &ring).unwrap(), &ideal).unwrap());
+    assert!(!in_ideal(&Polynomial::from_string("1x^1", &ring).unwrap(), &ideal).unwrap());
+    assert!(!in_ideal(&Polynomial::from_string("1x^2 + 2x^0", &ring).unwrap(), &ideal).unwrap());
+}
+
+#[test]
+fn ring_mismatch() {
+    let r1 = ring_over::<Integer>(&["x", "y"], MonomialOrdering::DegLex, ());
+    let r2 = ring_over::<Integer>(&["x", "z"], MonomialOrdering::DegLex, ());
+    let f = Polynomial::from_string("2x^1y^0 + 1x^0y^1", &r1).unwrap();
+    let g = Polynomial::from_string("3x^1z^0 + 1x^0z^1", &r2).unwrap();
+
+    assert!(normal_form(&f, &PolySet(vec![g.clone()])) == Err(PolyError::RingMismatch));
+    assert!(strong_grobner_basis(&PolySet(vec![f.clone(), g.clone()])) == Err(PolyError::RingMismatch));
+    assert!(in_ideal(&f, &PolySet(vec![g])) == Err(PolyError::RingMismatch));
+}
@@ -0,0 +1,52 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::operations::*;
+
+mod common;
+use common::ring_over;
+
+#[test]
+fn zp_arithmetic() {
+    let p = 32003;
+    let a = Zp::new(12345, p);
+    let b = Zp::new(-1, p);
+
+    assert!(b.value() == p - 1);
+    assert!(a.mul(&a.checked_inv().unwrap()) == Zp::one(&p));
+    assert!(a.add(&a.neg()).is_zero());
+    assert!(a.div(&b) == a.neg());
+    assert!(a.checked_div(&Zp::zero(&p)).is_none());
+
+    // 1/2 is the residue that doubles to 1.
+    let half = Zp::parse("1/2", &p).unwrap();
+    assert!(half.add(&half) == Zp::one(&p));
+    assert!(Zp::parse("-3", &p).unwrap() == Zp::new(-3, p));
+    assert!(Zp::parse("1/32003", &p).is_none());
+
+    assert!(is_prime(32003));
+    assert!(!is_prime(32001));
+}
+
+#[test]
+fn zp_basis_matches_rational() {
+    let ring = ring_over::<Zp>(&["x", "y", "z"], MonomialOrdering::DegRevLex, 32003);
+    let q = common::ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+
+    let fs = ["1x^2y^0z^0 + 3/2x^0y^1z^0 + -1x^0y^0z^1",
+              "1x^1y^1z^0 + -2x^0y^0z^1",
+              "1x^0y^2z^0 + 1x^0y^0z^2"];
+
+    let bp = grobner_basis(&PolySet(fs.iter().map(|f| Polynomial::from_string(f, &ring).unwrap()).collect())).unwrap();
+    let bq = grobner_basis(&PolySet(fs.iter().map(|f| Polynomial::from_string(f, &q).unwrap()).collect())).unwrap();
+
+    assert!(bp.0.len() == bq.0.len());
+
+    for (gp, gq) in bp.0.iter().zip(&bq.0) {
+        assert!(gp.terms.len() == gq.terms.len());
+        for (mp, mq) in gp.terms.iter().zip(&gq.terms) {
+            assert!(mp.degree == mq.degree);
+            let c = Zp::parse(&mq.coefficient.to_string(), &32003).unwrap();
+            assert!(mp.coefficient == c);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use polynomial_operations::modular::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+mod common;
+use common::ring;
+
+fn same_basis(g1: &PolySet, g2: &PolySet) -> bool {
+    g1.0.len() == g2.0.len() && g1.0.iter().all(|p| g2.0.contains(p))
+}
+
+#[test]
+fn modular_matches_rational() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+
+    let p1 = Polynomial::from_string("3x^2y^0z^0 + 2x^0y^1z^1 + -1/7x^0y^0z^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("5/3x^1y^1z^0 + -4x^0y^0z^1", &ring).unwrap();
+    let p3 = Polynomial::from_string("1x^0y^2z^0 + 11/2x^1y^0z^0 + -1x^0y^0z^1", &ring).unwrap();
+
+    let ps = PolySet(vec![p1, p2, p3]);
+
+    let g = grobner_basis_modular(&ps).unwrap();
+    assert!(same_basis(&g, &grobner_basis(&ps).unwrap()));
+
+    // The modular basis is sorted, so the order of the input doesn't
+    //   change it.
+    for perm in [[2, 1, 0], [1, 2, 0], [0, 2, 1]] {
+        let qs = PolySet(perm.iter().map(|i| ps.0[*i].clone()).collect());
+        assert!(grobner_basis_modular(&qs).unwrap() == g);
+    }
+}
+
+#[test]
+fn modular_lex_basis() {
+    let ring = ring(&["x", "y"], MonomialOrdering::Lex);
+
+    let p1 = Polynomial::from_string("1x^2y^0 + 1x^0y^2 + -1x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^1 + -1/4x^0y^0", &ring).unwrap();
+
+    let ps = PolySet(vec![p1, p2]);
+    let g = grobner_basis_modular(&ps).unwrap();
+
+    assert!(same_basis(&g, &grobner_basis(&ps).unwrap()));
+    for i in 1..g.0.len() {
+        assert!(g.0[i - 1].terms[0] > g.0[i].terms[0]);
+    }
+}
+
+#[test]
+fn modular_unit_ideal() {
+    let ring = ring(&["x", "y"], MonomialOrdering::DegLex);
+
+    let p1 = Polynomial::from_string("1x^1y^0 + -1x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^0 + -2x^0y^0", &ring).unwrap();
+
+    let g = grobner_basis_modular(&PolySet(vec![p1, p2])).unwrap();
+    let one = Polynomial::from_string("1x^0y^0", &ring).unwrap();
+
+    assert!(g.0 == vec![one]);
+}
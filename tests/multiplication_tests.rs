@@ -0,0 +1,61 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+mod common;
+use common::{ring, ring_over};
+
+// The product as a sum of one polynomial times each term of the other.
+fn schoolbook<F: Coefficient>(f: &Polynomial<F>, g: &Polynomial<F>) -> Polynomial<F> {
+    let mut c = Polynomial::zero(&f.ring);
+    for t in f.terms.iter() {
+        let p = Polynomial::from_monom(t.clone());
+        for u in g.terms.iter() {
+            c = add_polys(&c, &mult_polys(&p, &Polynomial::from_monom(u.clone())).unwrap()).unwrap();
+        }
+    }
+    c
+}
+
+#[test]
+fn dense_products() {
+    for ord in [MonomialOrdering::Lex, MonomialOrdering::DegLex, MonomialOrdering::DegRevLex] {
+        let ring = ring(&["x", "y", "z"], ord);
+        let f = Polynomial::from_string("1x^1y^0z^0 + 2x^0y^1z^0 + -3x^0y^0z^1 + 1x^0y^0z^0", &ring).unwrap();
+        let g = Polynomial::from_string("1x^2y^0z^0 + -1x^0y^1z^1 + 1/2x^0y^0z^0", &ring).unwrap();
+
+        let mut p = f.clone();
+        let mut q = f.clone();
+        for _ in 0..5 {
+            p = mult_polys(&p, &g).unwrap();
+            q = schoolbook(&q, &g);
+            assert!(p == q);
+            assert!(p.terms.windows(2).all(|w| w[0] > w[1]));
+        }
+        assert!(mult_polys(&p, &f).unwrap() == mult_polys(&f, &p).unwrap());
+    }
+}
+
+#[test]
+fn cancelling_products() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+    let f = Polynomial::from_string("1x^1y^0z^0 + 1x^0y^1z^0", &ring).unwrap();
+    let g = Polynomial::from_string("1x^1y^0z^0 + -1x^0y^1z^0", &ring).unwrap();
+    let h = Polynomial::from_string("1x^2y^0z^0 + -1x^0y^2z^0", &ring).unwrap();
+
+    assert!(mult_polys(&f, &g).unwrap() == h);
+
+    let zero = Polynomial::zero(&ring);
+    assert!(mult_polys(&f, &zero).unwrap().terms.is_empty());
+    assert!(mult_polys(&zero, &f).unwrap().terms.is_empty());
+}
+
+#[test]
+fn modular_products() {
+    let ring = ring_over::<Zp>(&["x", "y", "z"], MonomialOrdering::DegRevLex, 7);
+    let f = Polynomial::from_string("3x^3y^0z^0 + 5x^1y^1z^1 + 1x^0y^2z^0 + 6x^0y^0z^0", &ring).unwrap();
+    let g = Polynomial::from_string("4x^2y^1z^0 + 2x^0y^0z^3 + 1x^0y^0z^1", &ring).unwrap();
+
+    assert!(mult_polys(&f, &g).unwrap() == schoolbook(&f, &g));
+    assert!(mult_polys(&mult_polys(&f, &g).unwrap(), &f).unwrap() == mult_polys(&f, &mult_polys(&g, &f).unwrap()).unwrap());
+}
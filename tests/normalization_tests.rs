@@ -0,0 +1,75 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use rug::Rational;
+use std::sync::Arc;
+
+mod common;
+use common::ring;
+
+fn monom(c: i64, d: &[u16], ring: &Arc<Ring>) -> Monomial {
+    Monomial { coefficient: Rational::from(c), degree: d.into(), ring: Arc::clone(ring) }
+}
+
+#[test]
+fn new_normalizes() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let terms = vec![
+        monom(1, &[0, 0], &r),
+        monom(2, &[1, 1], &r),
+        monom(0, &[3, 0], &r),
+        monom(5, &[0, 2], &r),
+        monom(-2, &[1, 1], &r),
+        monom(4, &[0, 0], &r),
+    ];
+    let f = Polynomial::new(terms, &r).unwrap();
+
+    assert!(f.is_normalized());
+    assert!(f.length == 2);
+    assert!(f == Polynomial::from_string("5x^0y^2 + 5x^0y^0", &r).unwrap());
+
+    let g = Polynomial::from_string("1x^0y^0 + -1x^1y^0 + 3x^2y^1 + 1x^1y^0", &r).unwrap();
+    assert!(g.is_normalized());
+    assert!(g.terms.len() == 2);
+    assert!(g.terms[0] == monom(3, &[2, 1], &r));
+
+    assert!(Polynomial::new(Vec::new(), &r).unwrap() == Polynomial::zero(&r));
+    assert!(Polynomial::from_monom(monom(0, &[1, 0], &r)).terms.is_empty());
+}
+
+#[test]
+fn new_rejects_foreign_terms() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let s = ring(&["x", "z"], MonomialOrdering::DegRevLex);
+
+    let terms = vec![monom(1, &[1, 0], &r), monom(1, &[0, 1], &s)];
+    assert!(Polynomial::new(terms, &r) == Err(PolyError::RingMismatch));
+
+    let terms = vec![monom(1, &[1, 0, 2], &r)];
+    assert!(Polynomial::new(terms, &r) == Err(PolyError::LengthMismatch));
+
+    let narrow = Arc::new((*r).clone().with_width(ExponentWidth::U8));
+    let terms = vec![monom(1, &[200, 0], &narrow)];
+    assert!(Polynomial::new(terms, &narrow) == Err(PolyError::ExponentOverflow));
+}
+
+#[test]
+fn hand_built_polynomials() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial { length: 0, terms: vec![monom(1, &[0, 1], &r), monom(1, &[1, 1], &r)], ring: Arc::clone(&r) };
+    assert!(!f.is_normalized());
+
+    let g = Polynomial { length: 2, terms: f.terms.clone(), ring: Arc::clone(&r) };
+    assert!(!g.is_normalized());
+    assert!(Polynomial::new(g.terms, &r).unwrap().is_normalized());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not normalized")]
+fn operations_check_operands() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = Polynomial { length: 2, terms: vec![monom(1, &[0, 1], &r), monom(1, &[1, 1], &r)], ring: Arc::clone(&r) };
+    let _ = add_polys(&f, &f);
+}
@@ -0,0 +1,139 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::number_fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+
+use rug::Rational;
+
+mod common;
+use common::ring_over;
+
+fn q(v: &[i64]) -> Vec<Rational> {
+    v.iter().map(|c| Rational::from(*c)).collect()
+}
+
+#[test]
+fn sqrt2_arithmetic() {
+    // Q(sqrt(2)) = Q[a] / (a^2 - 2)
+    let k = NumberField::new(q(&[-2, 0, 1]), "a").unwrap();
+    let a = AlgebraicNumber::generator(&k);
+    let one = AlgebraicNumber::one(&k);
+    let two = one.add(&one);
+
+    assert!(k.degree() == 2);
+    assert!(a.mul(&a) == two);
+    assert!(a.checked_inv().unwrap() == a.div(&two));
+
+    // (1 + a)(a - 1) = 1
+    let u = one.add(&a);
+    assert!(u.checked_inv().unwrap() == a.sub(&one));
+    assert!(u.mul(&u.checked_inv().unwrap()) == one);
+}
+
+#[test]
+fn cyclotomic_inverses() {
+    // Q(zeta_5) = Q[z] / (z^4 + z^3 + z^2 + z + 1)
+    let k = NumberField::new(q(&[1, 1, 1, 1, 1]), "z").unwrap();
+    let z = AlgebraicNumber::generator(&k);
+    let one = AlgebraicNumber::one(&k);
+
+    let z5 = z.mul(&z).mul(&z).mul(&z).mul(&z);
+    assert!(z5 == one);
+
+    for c in [q(&[1, 2]), q(&[3, 0, -1, 5]), q(&[0, 0, 1])] {
+        let x = AlgebraicNumber::from_coeffs(c, &k);
+        assert!(x.mul(&x.checked_inv().unwrap()) == one);
+    }
+}
+
+#[test]
+fn minimal_polynomial_checks() {
+    // a^3 - 2 has no rational roots.
+    assert!(NumberField::new(q(&[-2, 0, 0, 1]), "a").is_ok());
+    // a^2 - 4 = (a - 2)(a + 2)
+    assert!(NumberField::new(q(&[-4, 0, 1]), "a").is_err());
+    // 4a^3 - 1/2 has the root 1/2.
+    let m = vec![Rational::from((-1, 2)), Rational::new(), Rational::new(), Rational::from(4)];
+    assert!(NumberField::new(m, "a").is_err());
+    // (a^2 + 1)^2 has no roots but is not squarefree.
+    assert!(NumberField::new(q(&[1, 0, 2, 0, 1]), "a").is_err());
+    assert!(NumberField::new(q(&[5]), "a").is_err());
+    assert!(NumberField::new(q(&[-2, 0, 1]), "2").is_err());
+}
+
+#[test]
+fn irreducibility_above_degree_three() {
+    let reducible = |m: &[i64]| matches!(NumberField::new(q(m), "a"), Err(FieldError::Reducible));
+
+    // a^4 - 5a^2 + 6 = (a^2 - 2)(a^2 - 3) has no rational roots.
+    assert!(reducible(&[6, 0, -5, 0, 1]));
+    // a^4 + 4 = (a^2 + 2a + 2)(a^2 - 2a + 2)
+    assert!(reducible(&[4, 0, 0, 0, 1]));
+    // (a^3 - 2)(a^3 + a + 1), with rational coefficients.
+    let m: Vec<Rational> = q(&[-2, -2, 0, -1, 1, 0, 1]).into_iter().map(|c| c / 3).collect();
+    assert!(matches!(NumberField::new(m, "a"), Err(FieldError::Reducible)));
+
+    // a^4 + 1 factors modulo every prime, but not over Q.
+    assert!(NumberField::new(q(&[1, 0, 0, 0, 1]), "a").is_ok());
+    assert!(NumberField::new(q(&[1, 1, 1, 1, 1]), "a").is_ok());
+    assert!(NumberField::new(q(&[-3, 0, 0, 0, 0, 0, 1, 1]), "a").is_ok());
+    // Swinnerton-Dyer's polynomial for sqrt(2), sqrt(3), sqrt(5).
+    assert!(NumberField::new(q(&[576, 0, -960, 0, 352, 0, -40, 0, 1]), "a").is_ok());
+
+    // Every nonzero element has an inverse.
+    let k = NumberField::new(q(&[1, 0, 0, 0, 1]), "a").unwrap();
+    let one = AlgebraicNumber::one(&k);
+    for c in [q(&[1, 1]), q(&[0, 1, 0, 1]), q(&[2, -1, 3])] {
+        let x = AlgebraicNumber::from_coeffs(c, &k);
+        assert!(x.mul(&x.checked_inv().unwrap()) == one);
+    }
+}
+
+#[test]
+fn large_constant_terms() {
+    let big = |s: &str| s.parse::<Rational>().unwrap();
+
+    // a^2 - (10^20 + 1) and a^3 - 2(10^20 + 1)
+    let m = vec![-big("100000000000000000001"), Rational::new(), Rational::from(1)];
+    assert!(NumberField::new(m, "a").is_ok());
+    let m = vec![-big("200000000000000000002"), Rational::new(), Rational::new(), Rational::from(1)];
+    assert!(NumberField::new(m, "a").is_ok());
+    // a^2 - 10^20 = (a - 10^10)(a + 10^10)
+    let m = vec![-big("100000000000000000000"), Rational::new(), Rational::from(1)];
+    assert!(matches!(NumberField::new(m, "a"), Err(FieldError::Reducible)));
+}
+
+#[test]
+fn number_field_parse_and_print() {
+    let k = NumberField::new(q(&[-2, 0, 1]), "a").unwrap();
+
+    let x = AlgebraicNumber::parse("a^3 - 1/2a + 3", &k).unwrap();
+    assert!(x.coeffs() == [Rational::from(3), Rational::from((3, 2))]);
+    assert!(x.to_string() == "(3/2a+3)");
+    assert!(AlgebraicNumber::parse("-a", &k).unwrap().to_string() == "(-a)");
+    assert!(AlgebraicNumber::parse("a^2", &k).unwrap().to_string() == "2");
+}
+
+#[test]
+fn number_field_basis() {
+    let k = NumberField::new(q(&[-2, 0, 1]), "a").unwrap();
+    let ring = ring_over::<AlgebraicNumber>(&["x", "y"], MonomialOrdering::Lex, k);
+
+    // x^2 - 2 and y - x vanish at x = y = +-sqrt(2), and x + y - 2a
+    //   picks out the root a, which only exists over Q(sqrt(2)).
+    let p1 = Polynomial::from_string("1x^2y^0 + -2x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^0y^1 + -1x^1y^0", &ring).unwrap();
+    let p3 = Polynomial::from_string("1x^1y^0 + 1x^0y^1 + (-2a)x^0y^0", &ring).unwrap();
+
+    let g = grobner_basis(&PolySet(vec![p1, p2, p3])).unwrap();
+
+    let x = Polynomial::from_string("1x^1y^0 + (-a)x^0y^0", &ring).unwrap();
+    let y = Polynomial::from_string("1x^0y^1 + (-a)x^0y^0", &ring).unwrap();
+
+    assert!(g.0.len() == 2);
+    assert!(g.0.contains(&x));
+    assert!(g.0.contains(&y));
+
+    let r = reduce(g.clone()).unwrap();
+    assert!(r.0.len() == 2);
+}
@@ -0,0 +1,247 @@
+use polynomial_operations::polynomials::*;
+use polynomial_operations::operations::*;
+
+use std::sync::Arc;
+
+mod common;
+use common::ring;
+
+#[test]
+fn grevlex_compare() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+
+    let xz = Monomial::from_string("x^1y^0z^1", Arc::clone(&ring)).unwrap();
+    let y2 = Monomial::from_string("x^0y^2z^0", Arc::clone(&ring)).unwrap();
+    let x2 = Monomial::from_string("x^2y^0z^0", Arc::clone(&ring)).unwrap();
+    let z3 = Monomial::from_string("x^0y^0z^3", Arc::clone(&ring)).unwrap();
+
+    assert!(y2 > xz);
+    assert!(x2 > y2);
+    assert!(z3 > x2);
+
+    let deglex = common::ring(&["x", "y", "z"], MonomialOrdering::DegLex);
+    let xz = Monomial::from_string("x^1y^0z^1", Arc::clone(&deglex)).unwrap();
+    let y2 = Monomial::from_string("x^0y^2z^0", Arc::clone(&deglex)).unwrap();
+
+    assert!(xz > y2);
+}
+
+#[test]
+fn grevlex_add_and_mult() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+
+    let p1 = Polynomial::from_string("1x^1y^0z^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^0y^2z^0", &ring).unwrap();
+    let p3 = add_polys(&p1, &p2).unwrap();
+
+    assert!(p3 == Polynomial::from_string("1x^0y^2z^0 + 1x^1y^0z^1", &ring).unwrap());
+
+    let p4 = mult_polys(&p3, &Polynomial::from_string("1x^1y^0z^0 + 1x^0y^0z^1", &ring).unwrap()).unwrap();
+    let p5 = Polynomial::from_string("1x^1y^2z^0 + 1x^2y^0z^1 + 1x^0y^2z^1 + 1x^1y^0z^2", &ring).unwrap();
+
+    assert!(p4 == p5);
+}
+
+#[test]
+fn grevlex_basis() {
+    let ring = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+
+    let p1 = Polynomial::from_string("1x^2y^0z^0 + -1x^0y^1z^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^3y^0z^0 + -1x^0y^0z^1", &ring).unwrap();
+
+    let ps = PolySet(vec![p1, p2]);
+    let mut b = grobner_basis(&ps).unwrap();
+
+    let g1 = Polynomial::from_string("1x^2y^0z^0 + -1x^0y^1z^0", &ring).unwrap();
+    let g2 = Polynomial::from_string("1x^1y^1z^0 + -1x^0y^0z^1", &ring).unwrap();
+    let g3 = Polynomial::from_string("1x^0y^2z^0 + -1x^1y^0z^1", &ring).unwrap();
+
+    for g in [g1, g2, g3].iter() {
+        assert!(b.0.contains(g));
+    }
+    assert!(b.0.len() == 3);
+
+    for p in ps.0 {
+        let (_, r) = divide_poly_set(&p, &mut b).unwrap();
+        assert!(r.terms.is_empty());
+    }
+}
+
+#[test]
+fn weighted_compare() {
+    let symbols = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let ring = Arc::new(Ring::weighted(symbols.clone(), vec![1, 2, 3], MonomialOrdering::Lex).unwrap());
+
+    let x3 = Monomial::from_string("x^3y^0z^0", Arc::clone(&ring)).unwrap();
+    let z1 = Monomial::from_string("x^0y^0z^1", Arc::clone(&ring)).unwrap();
+    let xy = Monomial::from_string("x^1y^1z^0", Arc::clone(&ring)).unwrap();
+    let y2 = Monomial::from_string("x^0y^2z^0", Arc::clone(&ring)).unwrap();
+
+    // x^3, xy and z all have weighted degree 3, so lex breaks the tie.
+    assert!(x3 > xy);
+    assert!(xy > z1);
+    assert!(y2 > x3);
+
+    let ring = Arc::new(Ring::weighted(symbols.clone(), vec![1, 2, 3], MonomialOrdering::DegRevLex).unwrap());
+    let x3 = Monomial::from_string("x^3y^0z^0", Arc::clone(&ring)).unwrap();
+    let z1 = Monomial::from_string("x^0y^0z^1", Arc::clone(&ring)).unwrap();
+
+    assert!(x3 > z1);
+
+    assert!(Ring::weighted(symbols, vec![1, 2], MonomialOrdering::Lex).is_err());
+}
+
+#[test]
+fn weighted_basis() {
+    let symbols = vec!["x".to_string(), "y".to_string()];
+    let ring = Arc::new(Ring::weighted(symbols, vec![2, 3], MonomialOrdering::Lex).unwrap());
+
+    // Quasi-homogeneous cusp: x^3 - y^2 has weighted degree 6.
+    let p1 = Polynomial::from_string("1x^0y^2 + -1x^3y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^1", &ring).unwrap();
+
+    let ps = PolySet(vec![p1, p2]);
+    let mut b = grobner_basis(&ps).unwrap();
+
+    for p in ps.0 {
+        let (_, r) = divide_poly_set(&p, &mut b).unwrap();
+        assert!(r.terms.is_empty());
+    }
+
+    let x4 = Polynomial::from_string("1x^4y^0", &ring).unwrap();
+    let (_, r) = divide_poly_set(&x4, &mut b).unwrap();
+    assert!(r.terms.is_empty());
+}
+
+#[test]
+fn block_compare() {
+    let symbols = vec!["t".to_string(), "x".to_string(), "y".to_string()];
+    let ring = Arc::new(Ring::block(symbols.clone(), 1, MonomialOrdering::DegRevLex, MonomialOrdering::DegLex).unwrap());
+
+    let t = Monomial::from_string("t^1x^0y^0", Arc::clone(&ring)).unwrap();
+    let x5 = Monomial::from_string("t^0x^5y^0", Arc::clone(&ring)).unwrap();
+    let tx = Monomial::from_string("t^1x^1y^0", Arc::clone(&ring)).unwrap();
+    let ty2 = Monomial::from_string("t^1x^0y^2", Arc::clone(&ring)).unwrap();
+
+    assert!(t > x5);
+    assert!(ty2 > tx);
+
+    assert!(Ring::block(symbols, 4, MonomialOrdering::Lex, MonomialOrdering::Lex).is_err());
+}
+
+#[test]
+fn eliminate_twisted_cubic() {
+    let ring = ring(&["x", "y", "t"], MonomialOrdering::DegLex);
+
+    // x = t^2, y = t^3, so the image is the cusp y^2 = x^3.
+    let p1 = Polynomial::from_string("-1x^0y^0t^2 + 1x^1y^0t^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("-1x^0y^0t^3 + 1x^0y^1t^0", &ring).unwrap();
+
+    let e = eliminate(&PolySet(vec![p1, p2]), &["t"]).unwrap();
+
+    let g = Polynomial::from_string("1x^3y^0t^0 + -1x^0y^2t^0", &ring).unwrap();
+
+    assert!(e == PolySet(vec![g]));
+}
+
+#[test]
+fn eliminate_in_lex() {
+    let ring = ring(&["t", "x", "y", "z"], MonomialOrdering::Lex);
+
+    // Eliminating t leaves x^2 - y and xy - z. Their grevlex basis adds
+    //   y^2 - xz, but lex needs xz - y^2 and y^3 - z^2 instead.
+    let p1 = Polynomial::from_string("1t^1x^0y^0z^0 + -1t^0x^0y^0z^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1t^0x^2y^0z^0 + -1t^0x^0y^1z^0", &ring).unwrap();
+    let p3 = Polynomial::from_string("1t^0x^1y^1z^0 + -1t^1x^0y^0z^0", &ring).unwrap();
+
+    let e = eliminate(&PolySet(vec![p1, p2, p3]), &["t"]).unwrap();
+
+    for i in 0..e.0.len() {
+        for j in i + 1..e.0.len() {
+            let s = s_poly(&e.0[i], &e.0[j]).unwrap();
+            assert!(divide_poly_set(&s, &mut e.clone()).unwrap().1.terms.is_empty());
+        }
+    }
+
+    let g1 = Polynomial::from_string("1t^0x^2y^0z^0 + -1t^0x^0y^1z^0", &ring).unwrap();
+    let g2 = Polynomial::from_string("1t^0x^1y^1z^0 + -1t^0x^0y^0z^1", &ring).unwrap();
+    let g3 = Polynomial::from_string("1t^0x^1y^0z^1 + -1t^0x^0y^2z^0", &ring).unwrap();
+    let g4 = Polynomial::from_string("1t^0x^0y^3z^0 + -1t^0x^0y^0z^2", &ring).unwrap();
+
+    assert!(e.0.len() == 4);
+    assert!(e.0.contains(&g1) && e.0.contains(&g2) && e.0.contains(&g3) && e.0.contains(&g4));
+}
+
+#[test]
+fn matrix_matches_grevlex() {
+    let symbols = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let rows = vec![vec![1, 1, 1], vec![0, 0, -1], vec![0, -1, 0]];
+    let ring = Ring::matrix(symbols, rows).unwrap();
+    let grevlex = MonomialOrdering::DegRevLex;
+
+    let mut degrees = Vec::new();
+    for a in 0..3 {
+        for b in 0..3 {
+            for c in 0..3 {
+                degrees.push(vec![a, b, c]);
+            }
+        }
+    }
+
+    for d1 in degrees.iter() {
+        for d2 in degrees.iter() {
+            assert!(ring.ord.compare(d1, d2) == grevlex.compare(d1, d2));
+        }
+    }
+}
+
+#[test]
+fn matrix_validation() {
+    let symbols = vec!["x".to_string(), "y".to_string()];
+
+    assert!(Ring::matrix(symbols.clone(), vec![vec![1, 1], vec![0, -1]]).is_ok());
+    assert!(Ring::matrix(symbols.clone(), vec![vec![1, 1]]).is_err());
+    assert!(Ring::matrix(symbols.clone(), vec![vec![1, 1], vec![2, 2]]).is_err());
+    assert!(Ring::matrix(symbols.clone(), vec![vec![1, -1], vec![0, 1]]).is_err());
+    assert!(Ring::matrix(symbols, vec![vec![0, 1], vec![-1, 0]]).is_err());
+}
+
+// Lex with the variables read from last to first, defined the way a
+//   downstream crate would.
+#[derive(Debug)]
+struct ReverseVarLex;
+
+impl TermOrder for ReverseVarLex {
+    fn compare(&self, d1: &[u64], d2: &[u64]) -> std::cmp::Ordering {
+        for (a, b) in d1.iter().zip(d2).rev() {
+            match a.cmp(b) {
+                std::cmp::Ordering::Equal => continue,
+                x => return x,
+            }
+        }
+        std::cmp::Ordering::Equal
+    }
+}
+
+#[test]
+fn custom_order_basis() {
+    let ring = Arc::new(Ring::custom(vec!["x".to_string(), "y".to_string()], ReverseVarLex));
+
+    let y = Monomial::from_string("x^0y^1", Arc::clone(&ring)).unwrap();
+    let x5 = Monomial::from_string("x^5y^0", Arc::clone(&ring)).unwrap();
+    assert!(y > x5);
+
+    let p1 = add_polys(&Polynomial::from_string("1x^1y^0", &ring).unwrap(),
+                       &Polynomial::from_string("-1x^0y^2", &ring).unwrap()).unwrap();
+    let p2 = add_polys(&Polynomial::from_string("1x^0y^3", &ring).unwrap(),
+                       &Polynomial::from_string("-1x^0y^0", &ring).unwrap()).unwrap();
+
+    let b = grobner_basis(&PolySet(vec![p1, p2])).unwrap();
+
+    let g1 = Polynomial::from_string("1x^0y^1 + -1x^2y^0", &ring).unwrap();
+    let g2 = Polynomial::from_string("1x^3y^0 + -1x^0y^0", &ring).unwrap();
+
+    assert!(b.0.len() == 2);
+    assert!(b.0.contains(&g1));
+    assert!(b.0.contains(&g2));
+}
@@ -0,0 +1,103 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::rational_functions::*;
+
+use std::sync::Arc;
+
+mod common;
+use common::ring_over;
+
+fn params(symbols: &[&str]) -> Arc<Ring> {
+    parameter_ring(symbols.iter().map(|s| s.to_string()).collect()).unwrap()
+}
+
+#[test]
+fn rational_function_arithmetic() {
+    let d = params(&["a", "b"]);
+    let a = RationalFunction::param("a", &d).unwrap();
+    let b = RationalFunction::param("b", &d).unwrap();
+
+    // (a^2 - b^2) / (a - b) = a + b
+    let f = a.mul(&a).sub(&b.mul(&b)).div(&a.sub(&b));
+    assert!(f == a.add(&b));
+
+    // 1/a + 1/b = (a + b) / ab
+    let g = a.checked_inv().unwrap().add(&b.checked_inv().unwrap());
+    assert!(g == a.add(&b).div(&a.mul(&b)));
+    assert!(g.mul(&a).mul(&b) == a.add(&b));
+
+    assert!(a.sub(&a).is_zero());
+    assert!(a.div(&a) == RationalFunction::one(&d));
+
+    assert!(RationalFunction::zero(&d).checked_inv().is_none());
+    assert!(RationalFunction::new(a.numer().clone(), Polynomial::zero(&d)) == Err(PolyError::DivisionByZero));
+}
+
+#[test]
+fn rational_function_parse_and_print() {
+    let d = params(&["a", "b"]);
+
+    let f = RationalFunction::parse("(a^2 - 1)/(a*b + b)", &d).unwrap();
+    assert!(f.to_string() == "((a-1)/(b))");
+    assert!(RationalFunction::parse(&f.to_string(), &d).unwrap() == f);
+
+    let g = RationalFunction::parse("2a^2*b - 1/2", &d).unwrap();
+    assert!(g.to_string() == "(2a^2*b-1/2)");
+    assert!(RationalFunction::parse("3/4", &d).unwrap().to_string() == "3/4");
+
+    assert!(RationalFunction::parse("c", &d).is_none());
+    assert!(RationalFunction::parse("(a)/(0)", &d).is_none());
+
+    // Parameter degrees are bounded well below the exponents of the
+    //   parameter ring, so products of parsed functions fit.
+    let big = RationalFunction::parse("a^2147483647", &d).unwrap();
+    assert!(big.mul(&big) == RationalFunction::parse("a^4294967294", &d).unwrap());
+    assert!(RationalFunction::parse("a^4294967296", &d).is_none());
+    assert!(parameter_ring(vec![String::from("a"), String::from("a")]).is_err());
+}
+
+#[test]
+fn parametric_basis() {
+    let d = params(&["a"]);
+    let ring = ring_over::<RationalFunction>(&["x", "y"], MonomialOrdering::Lex, Arc::clone(&d));
+
+    // <x - a, y - ax> has basis {x - a, y - a^2}.
+    let p1 = Polynomial::from_string("1x^1y^0 + (-a)x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^0y^1 + (-a)x^1y^0", &ring).unwrap();
+
+    let g = grobner_basis(&PolySet(vec![p1.clone(), p2])).unwrap();
+
+    let g2 = Polynomial::from_string("1x^0y^1 + (-a^2)x^0y^0", &ring).unwrap();
+
+    assert!(g.0.len() == 2);
+    assert!(g.0.contains(&p1));
+    assert!(g.0.contains(&g2));
+}
+
+#[test]
+fn generic_parameters() {
+    let d = params(&["a", "b"]);
+    let ring = ring_over::<RationalFunction>(&["x", "y"], MonomialOrdering::Lex, Arc::clone(&d));
+
+    // For generic a and b, ax + by = x - y = 0 only at the origin.
+    let p1 = Polynomial::from_string("(a)x^1y^0 + (b)x^0y^1", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^0 + -1x^0y^1", &ring).unwrap();
+
+    let g = grobner_basis(&PolySet(vec![p1, p2])).unwrap();
+
+    let x = Polynomial::from_string("1x^1y^0", &ring).unwrap();
+    let y = Polynomial::from_string("1x^0y^1", &ring).unwrap();
+
+    assert!(g.0.len() == 2);
+    assert!(g.0.contains(&x));
+    assert!(g.0.contains(&y));
+
+    // Division leaves a remainder with a rational function coefficient.
+    let f = Polynomial::from_string("1x^1y^0", &ring).unwrap();
+    let q = Polynomial::from_string("(a)x^1y^0 + (b)x^0y^1", &ring).unwrap();
+    let (_, r) = divide_poly_set(&f, &mut PolySet(vec![q])).unwrap();
+    let r0 = Polynomial::from_string("((-b)/(a))x^0y^1", &ring).unwrap();
+    assert!(r == r0);
+}
@@ -0,0 +1,124 @@
+use polynomial_operations::error::PolyError;
+use polynomial_operations::fields::*;
+use polynomial_operations::parser::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::poly;
+
+use std::sync::Arc;
+
+mod common;
+use common::{ring, ring_over};
+
+fn kind(r: Result<Polynomial, ParseError>) -> ParseErrorKind {
+    r.unwrap_err().kind
+}
+
+#[test]
+fn natural_syntax() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = poly!(r, "x^2 - 3/2*x*y + 1");
+    assert!(f == Polynomial::from_string("1x^2y^0 + -3/2x^1y^1 + 1x^0y^0", &r).unwrap());
+    assert!(f.is_normalized());
+
+    // Juxtaposition, repeated factors and signs.
+    assert!(poly!(r, "3x^2y") == poly!(r, "3 * x * x * y"));
+    assert!(poly!(r, "xy - yx") == Polynomial::zero(&r));
+    assert!(poly!(r, "- -x + -y") == poly!(r, "x - y"));
+    assert!(poly!(r, "2^3 x") == poly!(r, "8x"));
+
+    // Parentheses and powers of polynomials.
+    assert!(poly!(r, "(x + y)^2") == poly!(r, "x^2 + 2xy + y^2"));
+    assert!(poly!(r, "(x - 1)(x + 1)") == poly!(r, "x^2 - 1"));
+    assert!(poly!(r, "2(x + y)^0") == poly!(r, "2"));
+    assert!(poly!(r, "(1/2)x") == poly!(r, "1/2 x"));
+    assert!(poly!(r, "0") == Polynomial::zero(&r));
+
+    // Every u16 exponent fits the default lanes.
+    assert!(poly!(r, "x^40000 * x^25535").terms[0].degree == vec![65535, 0]);
+
+    // The older format still parses.
+    let s = "-1/2x^3y^0 + 4x^1y^2 + 7x^0y^0";
+    assert!(Polynomial::parse(s, &r).unwrap() == Polynomial::from_string(s, &r).unwrap());
+}
+
+#[test]
+fn display_round_trips() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = poly!(r, "x^3 - 1/2*x*y^2 + 5");
+    assert!(f.to_string() == "1x^3 + -1/2x^1y^2 + 5");
+    assert!(format!("{}", f.terms[1]) == "-1/2x^1y^2");
+    assert!(poly!(r, &f.to_string()) == f);
+    assert!(Polynomial::zero(&r).to_string() == "0");
+
+    let ps = PolySet(vec![f.clone(), poly!(r, "y")]);
+    assert!(ps.to_string() == "{ 1x^3 + -1/2x^1y^2 + 5, 1y^1 }");
+    assert!(PolySet::<rug::Rational>(vec![]).to_string() == "{}");
+
+    assert!(r.to_string() == "[x, y] grevlex");
+    for s in ["[x, y, z] lex", "[a] deglex", "[x, y] w(1, 2) grevlex", "[x, y] m(1, 1; 0, -1)", "[t, x, y] block(1, lex, w(2, 1) grevlex)", "[] lex"] {
+        let ring: Ring = s.parse().unwrap();
+        assert!(ring.to_string() == s);
+        assert!(ring.to_string().parse::<Ring>().unwrap() == ring);
+    }
+    assert!("[x, y] w(1, 2)".parse::<Ring>().unwrap().ord == MonomialOrdering::Weighted(vec![1, 2], Box::new(MonomialOrdering::Lex)));
+    assert!("degrevlex".parse::<MonomialOrdering>().unwrap() == MonomialOrdering::DegRevLex);
+}
+
+#[test]
+fn other_coefficient_fields() {
+    let g = ring_over::<GaussianRational>(&["x"], MonomialOrdering::Lex, ());
+    let f = poly!(g, "(1 - 2*i)x + i*x + (3/2+1/4i)");
+    assert!(f.terms[0].to_string() == "(1-i)x^1");
+    assert!(f.terms[1].to_string() == "(3/2+1/4i)");
+
+    let p = ring_over::<Zp>(&["x", "y"], MonomialOrdering::DegRevLex, 7);
+    assert!(poly!(p, "8x + 1/2") == poly!(p, "x + 4"));
+}
+
+#[test]
+fn clear_errors() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+
+    let e = Polynomial::parse("x^2 + z", &r).unwrap_err();
+    assert!(e == ParseError { position: 6, kind: ParseErrorKind::UnknownSymbol(String::from("z")) });
+    assert!(e.to_string() == "unknown variable or coefficient `z` at column 7");
+
+    assert!(kind(Polynomial::parse("", &r)) == ParseErrorKind::UnexpectedEnd);
+    assert!(kind(Polynomial::parse("x +", &r)) == ParseErrorKind::UnexpectedEnd);
+    assert!(kind(Polynomial::parse("(x + y", &r)) == ParseErrorKind::UnexpectedEnd);
+    assert!(kind(Polynomial::parse("x + y)", &r)) == ParseErrorKind::UnexpectedCharacter(')'));
+    assert!(kind(Polynomial::parse("1.5x", &r)) == ParseErrorKind::UnexpectedCharacter('.'));
+    assert!(kind(Polynomial::parse("x^y", &r)) == ParseErrorKind::UnexpectedCharacter('y'));
+    assert!(kind(Polynomial::parse("1/0 x", &r)) == ParseErrorKind::InvalidCoefficient(String::from("1/0")));
+    assert!(kind(Polynomial::parse("x^99999999999999999999", &r)) == ParseErrorKind::InvalidExponent(String::from("99999999999999999999")));
+    assert!(kind(Polynomial::parse("x^3000000000", &r)) == ParseErrorKind::ExponentOverflow);
+    assert!(kind(Polynomial::parse("x^2000000000 * x^2000000000", &r)) == ParseErrorKind::ExponentOverflow);
+
+    // Powers of polynomials are bounded by the lanes of the ring, and
+    //   powers of coefficients by a limit of their own.
+    assert!(kind(Polynomial::parse("(x^2 + y)^2000000000", &r)) == ParseErrorKind::ExponentOverflow);
+    assert!(kind(Polynomial::parse("x(x + 1)^2147483647", &r)) == ParseErrorKind::ExponentOverflow);
+    assert!(Polynomial::parse("2^2147483647", &r).unwrap_err() == ParseError { position: 2, kind: ParseErrorKind::ExponentOverflow });
+    assert!(kind(Polynomial::parse("(x - x + 2)^2147483647", &r)) == ParseErrorKind::ExponentOverflow);
+    assert!(kind(Polynomial::parse("(1/2)^65536 x", &r)) == ParseErrorKind::ExponentOverflow);
+    assert!(poly!(r, "(x - x + 1)^65535") == poly!(r, "1"));
+    let narrow = Arc::new((*r).clone().with_width(ExponentWidth::U8));
+    assert!(kind(Polynomial::parse("x^128", &narrow)) == ParseErrorKind::ExponentOverflow);
+    assert!(poly!(narrow, "3^128 x") == poly!(narrow, "3^64 * 3^64 x"));
+    assert!(poly!(narrow, "(x + y)^3 (x - y)^3") == poly!(narrow, "(x^2 - y^2)^3"));
+
+    assert!(matches!("[x, y] foo".parse::<Ring>(), Err(PolyError::Syntax(ParseError { position: 7, kind: ParseErrorKind::UnknownOrdering(_) }))));
+    assert!(matches!("[x, 2y] lex".parse::<Ring>(), Err(PolyError::Syntax(ParseError { kind: ParseErrorKind::InvalidVariable(_), .. }))));
+    assert!("[x, y, x] lex".parse::<Ring>() == Err(PolyError::Syntax(ParseError { position: 7, kind: ParseErrorKind::InvalidVariable(String::from("x")) })));
+    assert!(matches!("[x, y] w(1, a) lex".parse::<Ring>(), Err(PolyError::Syntax(ParseError { kind: ParseErrorKind::InvalidNumber(_), .. }))));
+    assert!("[x, y] w(1, 2, 3) lex".parse::<Ring>() == Err(PolyError::Ordering(OrderingError::WeightLength)));
+    assert!("[x, y] m(1, 1; 1, 1)".parse::<Ring>() == Err(PolyError::Ordering(OrderingError::MatrixRank)));
+    assert!("[x, y] block(1, w(1, 1) lex, lex)".parse::<Ring>() == Err(PolyError::Ordering(OrderingError::WeightLength)));
+}
+
+#[test]
+#[should_panic(expected = "Invalid polynomial \"x + q\": unknown variable or coefficient `q` at column 5")]
+fn macro_panics_on_bad_literals() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    poly!(r, "x + q");
+}
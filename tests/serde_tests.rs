@@ -0,0 +1,126 @@
+#![cfg(feature = "serde")]
+
+use polynomial_operations::fields::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::poly;
+
+use rug::{Integer, Rational};
+use std::sync::Arc;
+
+mod common;
+use common::{ring, ring_over};
+
+#[test]
+fn schema() {
+    let r = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+    let f = poly!(r, "x^2 - 3/2*x*y + 1");
+    assert!(serde_json::to_string(&*r).unwrap() == r#"{"symbols":["x","y"],"ordering":"grevlex","width":32}"#);
+    assert!(serde_json::to_string(&f).unwrap() == concat!(
+        r#"{"ring":{"symbols":["x","y"],"ordering":"grevlex","width":32},"#,
+        r#""terms":[{"coefficient":"1","exponents":[2,0]},{"coefficient":"-3/2","exponents":[1,1]},"#,
+        r#"{"coefficient":"1","exponents":[0,0]}]}"#,
+    ));
+    assert!(serde_json::to_string(&f.terms[1]).unwrap() == concat!(
+        r#"{"ring":{"symbols":["x","y"],"ordering":"grevlex","width":32},"#,
+        r#""coefficient":"-3/2","exponents":[1,1]}"#,
+    ));
+    assert!(serde_json::to_string(&PolySet::<Rational>(vec![])).unwrap() == r#"{"ring":null,"polynomials":[]}"#);
+}
+
+#[test]
+fn round_trips() {
+    for s in ["[x, y, z] lex", "[x, y] w(1, 2) grevlex", "[x, y] m(1, 1; 0, -1)", "[t, x, y] block(1, lex, grevlex)"] {
+        let r: Arc<Ring> = Arc::new(s.parse().unwrap());
+        let back: Ring = serde_json::from_str(&serde_json::to_string(&*r).unwrap()).unwrap();
+        assert!(back == *r);
+
+        let f = Polynomial::parse("-1/3x^70000 + 2/7", &r).unwrap();
+        let g: Polynomial = serde_json::from_str(&serde_json::to_string(&f).unwrap()).unwrap();
+        assert!(g == f && *g.ring == *r && g.is_normalized());
+
+        let m: Monomial = serde_json::from_str(&serde_json::to_string(&f.terms[0]).unwrap()).unwrap();
+        assert!(m == f.terms[0]);
+    }
+
+    let r = ring_over::<Integer>(&["x"], MonomialOrdering::Lex, ());
+    let f = poly!(r, "-12345678901234567890x + 3");
+    let g: Polynomial<Integer> = serde_json::from_str(&serde_json::to_string(&f).unwrap()).unwrap();
+    assert!(g == f);
+
+    let r = ring_over::<GaussianRational>(&["x"], MonomialOrdering::Lex, ());
+    let f = poly!(r, "(1/2 - i)x + i");
+    let g: Polynomial<GaussianRational> = serde_json::from_str(&serde_json::to_string(&f).unwrap()).unwrap();
+    assert!(g == f);
+}
+
+#[test]
+fn basis_shares_one_ring() {
+    let r = ring(&["x", "y", "z"], MonomialOrdering::DegRevLex);
+    let ps = PolySet(vec![poly!(r, "x^2 + y*z - 1/2"), poly!(r, "x*y - z^2"), poly!(r, "y^3 - 2x")]);
+    let gb = grobner_basis(&ps).unwrap();
+
+    let json = serde_json::to_string(&gb).unwrap();
+    let back: PolySet = serde_json::from_str(&json).unwrap();
+    assert!(back == gb);
+    assert!(back.0.windows(2).all(|w| Arc::ptr_eq(&w[0].ring, &w[1].ring)));
+    assert!(add_polys(&back.0[0], &gb.0[1]).is_ok());
+}
+
+#[test]
+fn rebuilds_term_order() {
+    // Terms out of order, repeated or zero are normalized.
+    let f: Polynomial = serde_json::from_str(r#"{
+        "ring": {"symbols": ["x", "y"], "ordering": "lex", "width": 32},
+        "terms": [
+            {"coefficient": "1", "exponents": [0, 3]},
+            {"coefficient": "1/2", "exponents": [1, 0]},
+            {"coefficient": "0", "exponents": [5, 5]},
+            {"coefficient": "1/2", "exponents": [1, 0]}
+        ]
+    }"#).unwrap();
+    assert!(f.is_normalized());
+    assert!(f == poly!(ring(&["x", "y"], MonomialOrdering::Lex), "x + y^3"));
+
+    // The same terms under another ordering come out in its order.
+    let g: Polynomial = serde_json::from_str(r#"{
+        "ring": {"symbols": ["x", "y"], "ordering": "grevlex", "width": 32},
+        "terms": [{"coefficient": "1", "exponents": [1, 0]}, {"coefficient": "1", "exponents": [0, 3]}]
+    }"#).unwrap();
+    assert!(g.terms[0].degree.to_vec() == vec![0, 3]);
+}
+
+#[test]
+fn rejects_bad_input() {
+    let err = |s: &str| serde_json::from_str::<Polynomial>(s).unwrap_err().to_string();
+    let ring = r#"{"symbols": ["x", "y"], "ordering": "lex", "width": 8}"#;
+
+    assert!(err(&format!(r#"{{"ring": {}, "terms": [{{"coefficient": "1/0", "exponents": [1, 0]}}]}}"#, ring))
+        .starts_with("Invalid coefficient `1/0`"));
+    assert!(err(&format!(r#"{{"ring": {}, "terms": [{{"coefficient": "1", "exponents": [1]}}]}}"#, ring))
+        .starts_with("Degree vectors have different lengths"));
+    assert!(err(&format!(r#"{{"ring": {}, "terms": [{{"coefficient": "1", "exponents": [128, 0]}}]}}"#, ring))
+        .starts_with("Exponent too large for the ring"));
+    assert!(err(r#"{"ring": {"symbols": ["x", "y"], "ordering": "w(1) lex", "width": 32}, "terms": []}"#)
+        .starts_with("Invalid monomial ordering: WeightLength"));
+    assert!(err(r#"{"ring": {"symbols": ["x"], "ordering": "plex", "width": 32}, "terms": []}"#)
+        .starts_with("Invalid ordering: unknown monomial ordering `plex`"));
+    assert!(err(r#"{"ring": {"symbols": ["x, y"], "ordering": "lex", "width": 32}, "terms": []}"#)
+        .starts_with("Invalid variable name `x, y`"));
+    assert!(err(r#"{"ring": {"symbols": ["x"], "ordering": "lex", "width": 12}, "terms": []}"#)
+        .starts_with("Invalid exponent width 12"));
+    assert!(serde_json::from_str::<PolySet>(r#"{"ring": null, "polynomials": [[]]}"#).is_err());
+
+    #[derive(Debug)]
+    struct Reverse;
+    impl TermOrder for Reverse {
+        fn compare(&self, a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+            b.cmp(a)
+        }
+    }
+    let r = Arc::new(Ring::custom(vec![String::from("x")], Reverse));
+    assert!(serde_json::to_string(&Polynomial::zero(&r)).is_err());
+
+    let (r1, r2) = (self::ring(&["x"], MonomialOrdering::Lex), self::ring(&["y"], MonomialOrdering::Lex));
+    assert!(serde_json::to_string(&PolySet(vec![poly!(r1, "x"), poly!(r2, "y")])).is_err());
+}
@@ -0,0 +1,46 @@
+use polynomial_operations::galois::*;
+use polynomial_operations::operations::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::rational_functions::*;
+
+use std::sync::Arc;
+use std::thread;
+
+mod common;
+use common::ring;
+
+fn is_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn core_types_are_send_sync() {
+    is_send_sync::<Ring>();
+    is_send_sync::<Monomial>();
+    is_send_sync::<Polynomial>();
+    is_send_sync::<PolySet>();
+    is_send_sync::<Polynomial<Gf>>();
+    is_send_sync::<Polynomial<RationalFunction>>();
+}
+
+#[test]
+fn bases_in_worker_threads() {
+    let ring = ring(&["x", "y"], MonomialOrdering::DegRevLex);
+
+    let p1 = Polynomial::from_string("1x^2y^0 + 1x^0y^2 + -1x^0y^0", &ring).unwrap();
+    let p2 = Polynomial::from_string("1x^1y^1 + -1/4x^0y^0", &ring).unwrap();
+    let ps = Arc::new(PolySet(vec![p1, p2]));
+
+    let expected = grobner_basis(&ps).unwrap();
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let ps = Arc::clone(&ps);
+            thread::spawn(move || grobner_basis(&ps).unwrap())
+        })
+        .collect();
+
+    for w in workers {
+        let g = w.join().unwrap();
+        assert!(g.0.len() == expected.0.len());
+        assert!(g.0.iter().all(|p| expected.0.contains(p)));
+    }
+}
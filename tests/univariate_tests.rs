@@ -0,0 +1,80 @@
+use polynomial_operations::fields::*;
+use polynomial_operations::polynomials::*;
+use polynomial_operations::univariate::*;
+
+use rug::Rational;
+
+mod common;
+use common::ring;
+
+fn q(v: &[i64]) -> DensePolynomial {
+    DensePolynomial::new(v.iter().map(|c| Rational::from(*c)).collect(), &())
+}
+
+#[test]
+fn dense_arithmetic() {
+    // (x + 1)(x - 1) = x^2 - 1
+    let a = q(&[1, 1]);
+    let b = q(&[-1, 1]);
+
+    assert!(a.mul(&b) == q(&[-1, 0, 1]));
+    assert!(a.add(&b) == q(&[0, 2]));
+    assert!(a.sub(&a).is_zero());
+    assert!(a.sub(&a).degree().is_none());
+    assert!(q(&[3, 0, 0]).degree() == Some(0));
+    assert!(a.mul(&DensePolynomial::zero(&())).is_zero());
+
+    // x^3 - 2x + 5 at x = 2
+    assert!(q(&[5, -2, 0, 1]).eval(&Rational::from(2)) == 9);
+}
+
+#[test]
+fn dense_division_and_gcd() {
+    let f = q(&[5, -2, 0, 3]);
+    let g = q(&[1, 2]);
+
+    let (d, r) = f.divmod(&g).unwrap();
+    assert!(d.mul(&g).add(&r) == f);
+    assert!(r.degree() == Some(0));
+
+    // (x - 1)^2 (x + 2) and (x - 1)(x + 3) share x - 1.
+    let a = q(&[-1, 1]).mul(&q(&[-1, 1])).mul(&q(&[2, 1]));
+    let b = q(&[-1, 1]).mul(&q(&[3, 1])).scalar_mult(&Rational::from(4));
+    assert!(a.gcd(&b) == q(&[-1, 1]));
+    assert!(q(&[1, 1]).gcd(&q(&[2])) == q(&[1]));
+}
+
+#[test]
+fn karatsuba_matches_schoolbook() {
+    let p = 32003;
+    let d = |n: usize, s: i64| DensePolynomial::new((0..n as i64).map(|i| Zp::new(i * s + 7, p)).collect(), &p);
+
+    for (n, m) in [(100, 100), (150, 37), (64, 65), (1, 90)] {
+        let a = d(n, 31);
+        let b = d(m, 17);
+        let c = a.mul(&b);
+
+        let mut v = vec![Zp::zero(&p); n + m - 1];
+        for (i, x) in a.coeffs().iter().enumerate() {
+            for (j, y) in b.coeffs().iter().enumerate() {
+                v[i + j] = v[i + j].add(&x.mul(y));
+            }
+        }
+        assert!(c == DensePolynomial::new(v, &p));
+    }
+}
+
+#[test]
+fn sparse_conversions() {
+    let ring = ring(&["x"], MonomialOrdering::Lex);
+    let f = Polynomial::from_string("3x^4 + -1x^2 + 1/2x^0", &ring).unwrap();
+
+    let d = DensePolynomial::from_poly(&f).unwrap();
+    assert!(d.coeffs() == [Rational::from((1, 2)), Rational::new(), Rational::from(-1), Rational::new(), Rational::from(3)]);
+    assert!(d.to_poly(&ring).unwrap() == f);
+
+    let ring2 = common::ring(&["x", "y"], MonomialOrdering::Lex);
+    let g = Polynomial::from_string("1x^1y^1", &ring2).unwrap();
+    assert!(DensePolynomial::from_poly(&g).is_none());
+    assert!(d.to_poly(&ring2).is_none());
+}